@@ -0,0 +1,53 @@
+use std::fmt::Write;
+
+/// Generates `ASSERT_COUNT` (2000 by default) generic functions with two assertions each, along with an instantiation of each.
+///
+/// `EXPANSION` selects how the assertions are written:
+/// - `macros` (the default): with `static_assert!`.
+/// - `formatted`: with `static_assert!`, formatting the value of `N` into the message.
+/// - `baseline`: written out the way `static_assert!` of the original proc-macro crate expanded them, with an `Assert` struct each.
+fn main() {
+    println!("cargo:rerun-if-env-changed=ASSERT_COUNT");
+    println!("cargo:rerun-if-env-changed=EXPANSION");
+    let count: usize = std::env::var("ASSERT_COUNT").ok().and_then(|count| count.parse().ok()).unwrap_or(2000);
+    let expansion = std::env::var("EXPANSION").unwrap_or_else(|_| "macros".to_string());
+
+    let mut source = String::new();
+    if expansion != "baseline" {
+        writeln!(source, "use static_assert_generic::*;").unwrap();
+    }
+    for i in 0..count {
+        // (generics list, declared generics, generic arguments, fields of the `Assert` struct, condition)
+        let checks = [
+            ("N: usize, T", "const N: usize, T", "N, T", "core::marker::PhantomData<T>", "N * std::mem::size_of::<T>() <= 4096".to_string()),
+            ("N: usize", "const N: usize", "N", "", format!("N <= {i}")),
+        ];
+
+        writeln!(source, "fn assert_{i}<const N: usize, T>() {{").unwrap();
+        for (generics, definitions, arguments, fields, condition) in checks {
+            match expansion.as_str() {
+                "baseline" => {
+                    writeln!(source, "    _ = {{").unwrap();
+                    writeln!(source, "        struct Assert<{definitions}>({fields});").unwrap();
+                    writeln!(source, "        impl<{definitions}> Assert<{arguments}> {{").unwrap();
+                    writeln!(source, "            #[allow(unused)]").unwrap();
+                    writeln!(source, "            const CHECK: () = if !({condition}) {{ panic!(\"N is too large!\") }};").unwrap();
+                    writeln!(source, "        }}").unwrap();
+                    writeln!(source, "        Assert::<{arguments}>::CHECK").unwrap();
+                    writeln!(source, "    }};").unwrap();
+                }
+                "formatted" => writeln!(source, "    static_assert!(({generics}) {condition} => \"N = {{N}} is too large!\");").unwrap(),
+                _ => writeln!(source, "    static_assert!(({generics}) {condition} => \"N is too large!\");").unwrap(),
+            }
+        }
+        writeln!(source, "}}").unwrap();
+    }
+    writeln!(source, "fn instantiate() {{").unwrap();
+    for i in 0..count {
+        writeln!(source, "    assert_{i}::<{i}, u8>();").unwrap();
+    }
+    writeln!(source, "}}").unwrap();
+
+    let path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("assertions.rs");
+    std::fs::write(path, source).unwrap();
+}
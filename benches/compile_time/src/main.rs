@@ -0,0 +1,7 @@
+//! Compile-time benchmark of a few thousand assertions, see the README.
+
+include!(concat!(env!("OUT_DIR"), "/assertions.rs"));
+
+fn main() {
+    instantiate();
+}
@@ -0,0 +1,22 @@
+use std::process::Command;
+
+/// Enables the `inline_const` cfg on compilers with inline `const { }` blocks (Rust 1.79 and newer),
+/// and the `diagnostic_namespace` cfg on those with `#[diagnostic::on_unimplemented]` (Rust 1.78 and newer).
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(inline_const)");
+    println!("cargo:rustc-check-cfg=cfg(diagnostic_namespace)");
+    println!("cargo:rustc-check-cfg=cfg(static_assert_generic_no_inline_const)");
+    println!("cargo:rerun-if-env-changed=RUSTC");
+
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let minor = Command::new(rustc).arg("--version").output().ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|version| version.split('.').nth(1)?.parse::<u32>().ok());
+
+    if minor.is_some_and(|minor| minor >= 78) {
+        println!("cargo:rustc-cfg=diagnostic_namespace");
+    }
+    if minor.is_some_and(|minor| minor >= 79) {
+        println!("cargo:rustc-cfg=inline_const");
+    }
+}
@@ -0,0 +1,18 @@
+// explicitly_drop!(C: u8)
+
+impl Drop for InlineConst {
+    fn drop(&mut self) {
+        const { panic!() }
+    }
+}
+impl Drop for WithoutInlineConst {
+    fn drop(&mut self) {
+        _ = {
+            struct Assert<const C: u8>;
+            impl<const C: u8> Assert<C> {
+                const MANUAL_DROP: () = panic!();
+            }
+            Assert::<C>::MANUAL_DROP
+        };
+    }
+}
@@ -0,0 +1,36 @@
+// explicitly_drop!(C: u8 => "C = {C} must be dropped explicitly!")
+
+impl Drop for InlineConst {
+    fn drop(&mut self) {
+        const {
+            {
+                let message = ::static_assert_generic::__private::Message::<
+                    72usize,
+                >::new()
+                    .push_str("C = ")
+                    .push_value(C)
+                    .push_str(" must be dropped explicitly!");
+                panic!("{}", message.as_str())
+            }
+        }
+    }
+}
+impl Drop for WithoutInlineConst {
+    fn drop(&mut self) {
+        _ = {
+            struct Assert<const C: u8>;
+            impl<const C: u8> Assert<C> {
+                const MANUAL_DROP: () = {
+                    let message = ::static_assert_generic::__private::Message::<
+                        72usize,
+                    >::new()
+                        .push_str("C = ")
+                        .push_value(C)
+                        .push_str(" must be dropped explicitly!");
+                    panic!("{}", message.as_str())
+                };
+            }
+            Assert::<C>::MANUAL_DROP
+        };
+    }
+}
@@ -0,0 +1,18 @@
+// explicitly_drop!(T)
+
+impl Drop for InlineConst {
+    fn drop(&mut self) {
+        const { panic!() }
+    }
+}
+impl Drop for WithoutInlineConst {
+    fn drop(&mut self) {
+        _ = {
+            struct Assert<T>(core::marker::PhantomData<T>);
+            impl<T> Assert<T> {
+                const MANUAL_DROP: () = panic!();
+            }
+            Assert::<T>::MANUAL_DROP
+        };
+    }
+}
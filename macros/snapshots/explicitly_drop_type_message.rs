@@ -0,0 +1,20 @@
+// explicitly_drop!(T => "Allocation must be freed explicitly!")
+
+impl Drop for InlineConst {
+    fn drop(&mut self) {
+        const { panic!("{}", "Allocation must be freed explicitly!") }
+    }
+}
+impl Drop for WithoutInlineConst {
+    fn drop(&mut self) {
+        _ = {
+            struct Assert<T>(core::marker::PhantomData<T>);
+            impl<T> Assert<T> {
+                const MANUAL_DROP: () = panic!(
+                    "{}", "Allocation must be freed explicitly!"
+                );
+            }
+            Assert::<T>::MANUAL_DROP
+        };
+    }
+}
@@ -0,0 +1,111 @@
+// static_assert!(<'a, 'b, T, U> std::mem::size_of::<&'a T>() == std::mem::size_of::<&'b [U]>())
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const T, *const U)>;
+            if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(std::mem::size_of::
+                                    < & 'a T > () == std::mem::size_of:: < & 'b[U] > ()), " (",
+                                    file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(std::mem::size_of::
+                                < & 'a T > () == std::mem::size_of:: < & 'b[U] > ()), " (",
+                                file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = std::mem::size_of::<&'a T>();
+                        let right: usize = std::mem::size_of::<&'b [U]>();
+                        let holds = left == right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<'a, 'b, T, U>(
+            core::marker::PhantomData<T>,
+            core::marker::PhantomData<U>,
+            core::marker::PhantomData<&'a ()>,
+            core::marker::PhantomData<&'b ()>,
+        );
+        impl<'a, 'b, T, U> Assert<'a, 'b, T, U>
+        where
+            T: 'a,
+            U: 'b,
+        {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(std::mem::size_of::
+                                    < & 'a T > () == std::mem::size_of:: < & 'b[U] > ()), " (",
+                                    file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(std::mem::size_of::
+                                < & 'a T > () == std::mem::size_of:: < & 'b[U] > ()), " (",
+                                file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = std::mem::size_of::<&'a T>();
+                        let right: usize = std::mem::size_of::<&'b [U]>();
+                        let holds = left == right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<'a, 'b, T, U>::CHECK)
+    };
+}
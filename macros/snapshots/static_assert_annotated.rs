@@ -0,0 +1,47 @@
+// static_assert!((N: usize) N <= 64 => "N = {N} is too large!", help: "use a Vec instead", note: "N is the length of an array")
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED {
+                if !(N <= 64) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            116usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is too large!")
+                            .push_str("\nhelp: use a Vec instead")
+                            .push_str("\nnote: N is the length of an array");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: usize>();
+        impl<const N: usize> Assert<N> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(N <= 64) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            116usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is too large!")
+                            .push_str("\nhelp: use a Vec instead")
+                            .push_str("\nnote: N is the length of an array");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<N>::CHECK)
+    };
+}
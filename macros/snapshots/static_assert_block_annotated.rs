@@ -0,0 +1,112 @@
+// static_assert!((N: usize, T) { N > 0 => "N must be non-zero!", note: "N is a divisor", std::mem::size_of::<T>() <= N })
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const T,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                if !(N > 0) {
+                    panic!("{}", "N must be non-zero!\nnote: N is a divisor")
+                }
+            }
+        };
+        const {
+            _ = core::marker::PhantomData::<(*const T,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(std::mem::size_of::
+                                    < T > () <= N), " (", file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(std::mem::size_of::
+                                < T > () <= N), " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = std::mem::size_of::<T>();
+                        let right: usize = N;
+                        let holds = left <= right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: usize, T>(core::marker::PhantomData<T>);
+        impl<const N: usize, T> Assert<N, T> {
+            #[allow(unused)]
+            const CHECK_0: () = if ::static_assert_generic::__private::ENABLED {
+                if !(N > 0) {
+                    panic!("{}", "N must be non-zero!\nnote: N is a divisor")
+                }
+            };
+            #[allow(unused)]
+            const CHECK_1: () = if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(std::mem::size_of::
+                                    < T > () <= N), " (", file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(std::mem::size_of::
+                                < T > () <= N), " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = std::mem::size_of::<T>();
+                        let right: usize = N;
+                        let holds = left <= right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<N, T>::CHECK_0, Assert::<N, T>::CHECK_1)
+    };
+}
@@ -0,0 +1,31 @@
+// static_assert!(#[cfg(debug_assertions)] #[cfg(feature = "strict")] (N: usize) N != 0 => "N must be non-zero!")
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED && cfg!(debug_assertions)
+                && cfg!(feature = "strict")
+            {
+                if !(N != 0) {
+                    panic!("{}", "N must be non-zero!")
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: usize>();
+        impl<const N: usize> Assert<N> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED
+                && cfg!(debug_assertions) && cfg!(feature = "strict")
+            {
+                if !(N != 0) {
+                    panic!("{}", "N must be non-zero!")
+                }
+            };
+        }
+        (Assert::<N>::CHECK)
+    };
+}
@@ -0,0 +1,259 @@
+// static_assert!((N: usize, M: usize) N > 0 && (M < N || !(M == 0)) && N % M == 0)
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            428usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(N > 0 && (M < N || !
+                                    (M == 0)) && N % M == 0), " (", file!(), ":", line!(), ")"
+                                )
+                                    .len() + stringify!(N > 0).len() + stringify!(M < N).len()
+                                + stringify!(! (M == 0)).len()
+                                + stringify!(N % M == 0).len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(N > 0 && (M < N || !
+                                (M == 0)) && N % M == 0), " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let (holds, message) = {
+                            let (holds, message) = {
+                                let left: usize = N;
+                                let right: usize = 0;
+                                let holds = left > right;
+                                (
+                                    holds,
+                                    if holds {
+                                        message
+                                    } else {
+                                        message
+                                            .push_str("\nfailed: ")
+                                            .push_str(stringify!(N > 0))
+                                            .push_str("\n  left: ")
+                                            .push_value(left)
+                                            .push_str("\n right: ")
+                                            .push_value(right)
+                                    },
+                                )
+                            };
+                            if holds {
+                                {
+                                    let original = message;
+                                    let (holds, message) = {
+                                        let left: usize = M;
+                                        let right: usize = N;
+                                        let holds = left < right;
+                                        (
+                                            holds,
+                                            if holds {
+                                                message
+                                            } else {
+                                                message
+                                                    .push_str("\nfailed: ")
+                                                    .push_str(stringify!(M < N))
+                                                    .push_str("\n  left: ")
+                                                    .push_value(left)
+                                                    .push_str("\n right: ")
+                                                    .push_value(right)
+                                            },
+                                        )
+                                    };
+                                    if holds {
+                                        (true, original)
+                                    } else {
+                                        let (holds, message) = {
+                                            let left: usize = M;
+                                            let right: usize = 0;
+                                            let holds = !(left == right);
+                                            (
+                                                holds,
+                                                if holds {
+                                                    message
+                                                } else {
+                                                    message
+                                                        .push_str("\nfailed: ")
+                                                        .push_str(stringify!(! (M == 0)))
+                                                        .push_str("\n  left: ")
+                                                        .push_value(left)
+                                                        .push_str("\n right: ")
+                                                        .push_value(right)
+                                                },
+                                            )
+                                        };
+                                        if holds { (true, original) } else { (false, message) }
+                                    }
+                                }
+                            } else {
+                                (false, message)
+                            }
+                        };
+                        if holds {
+                            {
+                                let left: usize = N % M;
+                                let right: usize = 0;
+                                let holds = left == right;
+                                (
+                                    holds,
+                                    if holds {
+                                        message
+                                    } else {
+                                        message
+                                            .push_str("\nfailed: ")
+                                            .push_str(stringify!(N % M == 0))
+                                            .push_str("\n  left: ")
+                                            .push_value(left)
+                                            .push_str("\n right: ")
+                                            .push_value(right)
+                                    },
+                                )
+                            }
+                        } else {
+                            (false, message)
+                        }
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: usize, const M: usize>();
+        impl<const N: usize, const M: usize> Assert<N, M> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            428usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(N > 0 && (M < N || !
+                                    (M == 0)) && N % M == 0), " (", file!(), ":", line!(), ")"
+                                )
+                                    .len() + stringify!(N > 0).len() + stringify!(M < N).len()
+                                + stringify!(! (M == 0)).len()
+                                + stringify!(N % M == 0).len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(N > 0 && (M < N || !
+                                (M == 0)) && N % M == 0), " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let (holds, message) = {
+                            let (holds, message) = {
+                                let left: usize = N;
+                                let right: usize = 0;
+                                let holds = left > right;
+                                (
+                                    holds,
+                                    if holds {
+                                        message
+                                    } else {
+                                        message
+                                            .push_str("\nfailed: ")
+                                            .push_str(stringify!(N > 0))
+                                            .push_str("\n  left: ")
+                                            .push_value(left)
+                                            .push_str("\n right: ")
+                                            .push_value(right)
+                                    },
+                                )
+                            };
+                            if holds {
+                                {
+                                    let original = message;
+                                    let (holds, message) = {
+                                        let left: usize = M;
+                                        let right: usize = N;
+                                        let holds = left < right;
+                                        (
+                                            holds,
+                                            if holds {
+                                                message
+                                            } else {
+                                                message
+                                                    .push_str("\nfailed: ")
+                                                    .push_str(stringify!(M < N))
+                                                    .push_str("\n  left: ")
+                                                    .push_value(left)
+                                                    .push_str("\n right: ")
+                                                    .push_value(right)
+                                            },
+                                        )
+                                    };
+                                    if holds {
+                                        (true, original)
+                                    } else {
+                                        let (holds, message) = {
+                                            let left: usize = M;
+                                            let right: usize = 0;
+                                            let holds = !(left == right);
+                                            (
+                                                holds,
+                                                if holds {
+                                                    message
+                                                } else {
+                                                    message
+                                                        .push_str("\nfailed: ")
+                                                        .push_str(stringify!(! (M == 0)))
+                                                        .push_str("\n  left: ")
+                                                        .push_value(left)
+                                                        .push_str("\n right: ")
+                                                        .push_value(right)
+                                                },
+                                            )
+                                        };
+                                        if holds { (true, original) } else { (false, message) }
+                                    }
+                                }
+                            } else {
+                                (false, message)
+                            }
+                        };
+                        if holds {
+                            {
+                                let left: usize = N % M;
+                                let right: usize = 0;
+                                let holds = left == right;
+                                (
+                                    holds,
+                                    if holds {
+                                        message
+                                    } else {
+                                        message
+                                            .push_str("\nfailed: ")
+                                            .push_str(stringify!(N % M == 0))
+                                            .push_str("\n  left: ")
+                                            .push_value(left)
+                                            .push_str("\n right: ")
+                                            .push_value(right)
+                                    },
+                                )
+                            }
+                        } else {
+                            (false, message)
+                        }
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<N, M>::CHECK)
+    };
+}
@@ -0,0 +1,97 @@
+// static_assert!((N: usize) N != 0)
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(N != 0), " (",
+                                    file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(N != 0), " (",
+                                file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = N;
+                        let right: usize = 0;
+                        let holds = left != right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: usize>();
+        impl<const N: usize> Assert<N> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(N != 0), " (",
+                                    file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(N != 0), " (",
+                                file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = N;
+                        let right: usize = 0;
+                        let holds = left != right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<N>::CHECK)
+    };
+}
@@ -0,0 +1,47 @@
+// static_assert!((N: usize, C: char) N <= 64 => "N must be <= 64, got {N} (for {C})")
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED {
+                if !(N <= 64) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            108usize,
+                        >::new()
+                            .push_str("N must be <= 64, got ")
+                            .push_value(N)
+                            .push_str(" (for ")
+                            .push_value(C)
+                            .push_str(")");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: usize, const C: char>();
+        impl<const N: usize, const C: char> Assert<N, C> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(N <= 64) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            108usize,
+                        >::new()
+                            .push_str("N must be <= 64, got ")
+                            .push_value(N)
+                            .push_str(" (for ")
+                            .push_value(C)
+                            .push_str(")");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<N, C>::CHECK)
+    };
+}
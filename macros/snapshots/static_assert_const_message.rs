@@ -0,0 +1,27 @@
+// static_assert!((N: usize) N != 0 => "N must be non-zero!")
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED {
+                if !(N != 0) {
+                    panic!("{}", "N must be non-zero!")
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: usize>();
+        impl<const N: usize> Assert<N> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(N != 0) {
+                    panic!("{}", "N must be non-zero!")
+                }
+            };
+        }
+        (Assert::<N>::CHECK)
+    };
+}
@@ -0,0 +1,75 @@
+// static_assert!(() 1 + 2 < 17)
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            0usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(1 + 2 < 17), " (",
+                                    file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(1 + 2 < 17), " (",
+                                file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left = 1 + 2;
+                        let right = 17;
+                        let holds = left < right;
+                        (holds, if holds { message } else { message })
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert();
+        impl Assert {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            0usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(1 + 2 < 17), " (",
+                                    file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(1 + 2 < 17), " (",
+                                file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left = 1 + 2;
+                        let right = 17;
+                        let holds = left < right;
+                        (holds, if holds { message } else { message })
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::CHECK)
+    };
+}
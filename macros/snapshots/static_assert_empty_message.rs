@@ -0,0 +1,27 @@
+// static_assert!(() 1 + 2 < 17 => "Math is broken!")
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED {
+                if !(1 + 2 < 17) {
+                    panic!("{}", "Math is broken!")
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert();
+        impl Assert {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(1 + 2 < 17) {
+                    panic!("{}", "Math is broken!")
+                }
+            };
+        }
+        (Assert::CHECK)
+    };
+}
@@ -0,0 +1,101 @@
+// static_assert!((T, 'a) std::mem::size_of::<&'a T>() == 8)
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const T,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(std::mem::size_of::
+                                    < & 'a T > () == 8), " (", file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(std::mem::size_of::
+                                < & 'a T > () == 8), " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = std::mem::size_of::<&'a T>();
+                        let right: usize = 8;
+                        let holds = left == right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<'a, T>(
+            core::marker::PhantomData<T>,
+            core::marker::PhantomData<&'a T>,
+        );
+        impl<'a, T> Assert<'a, T> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(std::mem::size_of::
+                                    < & 'a T > () == 8), " (", file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(std::mem::size_of::
+                                < & 'a T > () == 8), " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = std::mem::size_of::<&'a T>();
+                        let right: usize = 8;
+                        let holds = left == right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<'a, T>::CHECK)
+    };
+}
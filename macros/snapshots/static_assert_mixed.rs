@@ -0,0 +1,109 @@
+// static_assert!((N: usize, T, U?, 'a) N == std::mem::size_of::<&'a U>() / std::mem::size_of::<T>())
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const T, *const U)>;
+            if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(N ==
+                                    std::mem::size_of:: < & 'a U > () / std::mem::size_of:: < T
+                                    > ()), " (", file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(N ==
+                                std::mem::size_of:: < & 'a U > () / std::mem::size_of:: < T
+                                > ()), " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = N;
+                        let right: usize = std::mem::size_of::<&'a U>()
+                            / std::mem::size_of::<T>();
+                        let holds = left == right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<'a, const N: usize, T, U: ?Sized>(
+            core::marker::PhantomData<T>,
+            core::marker::PhantomData<U>,
+            core::marker::PhantomData<&'a T>,
+            core::marker::PhantomData<&'a U>,
+        );
+        impl<'a, const N: usize, T, U: ?Sized> Assert<'a, N, T, U> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            98usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(N ==
+                                    std::mem::size_of:: < & 'a U > () / std::mem::size_of:: < T
+                                    > ()), " (", file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(N ==
+                                std::mem::size_of:: < & 'a U > () / std::mem::size_of:: < T
+                                > ()), " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left: usize = N;
+                        let right: usize = std::mem::size_of::<&'a U>()
+                            / std::mem::size_of::<T>();
+                        let holds = left == right;
+                        (
+                            holds,
+                            if holds {
+                                message
+                            } else {
+                                message
+                                    .push_str("\n  left: ")
+                                    .push_value(left)
+                                    .push_str("\n right: ")
+                                    .push_value(right)
+                            },
+                        )
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<'a, N, T, U>::CHECK)
+    };
+}
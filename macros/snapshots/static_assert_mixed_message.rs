@@ -0,0 +1,49 @@
+// static_assert!((N: usize, T, U?, 'a) N == std::mem::size_of::<&'a U>() / std::mem::size_of::<T>() => "N = {N} is wrong!")
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const T, *const U)>;
+            if ::static_assert_generic::__private::ENABLED {
+                if !(N == std::mem::size_of::<&'a U>() / std::mem::size_of::<T>()) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            54usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is wrong!");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<'a, const N: usize, T, U: ?Sized>(
+            core::marker::PhantomData<T>,
+            core::marker::PhantomData<U>,
+            core::marker::PhantomData<&'a T>,
+            core::marker::PhantomData<&'a U>,
+        );
+        impl<'a, const N: usize, T, U: ?Sized> Assert<'a, N, T, U> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(N == std::mem::size_of::<&'a U>() / std::mem::size_of::<T>()) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            54usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is wrong!");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<'a, N, T, U>::CHECK)
+    };
+}
@@ -0,0 +1,104 @@
+// static_assert!((N: usize, T) { N is power_of_two, N is multiple_of(std::mem::align_of::<T>()), N fits u16 => "N = {N} is too large!" })
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const T,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                if !(N > 0 && N.count_ones() == 1) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            66usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is not a power of two");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+        const {
+            _ = core::marker::PhantomData::<(*const T,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                if !(N % std::mem::align_of::<T>() == 0) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            91usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is not a multiple of std::mem::align_of::<T>()");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+        const {
+            _ = core::marker::PhantomData::<(*const T,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                if !(::static_assert_generic::__private::fits::<u16, _>(N)) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            58usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is too large!");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: usize, T>(core::marker::PhantomData<T>);
+        impl<const N: usize, T> Assert<N, T> {
+            #[allow(unused)]
+            const CHECK_0: () = if ::static_assert_generic::__private::ENABLED {
+                if !(N > 0 && N.count_ones() == 1) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            66usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is not a power of two");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+            #[allow(unused)]
+            const CHECK_1: () = if ::static_assert_generic::__private::ENABLED {
+                if !(N % std::mem::align_of::<T>() == 0) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            91usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is not a multiple of std::mem::align_of::<T>()");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+            #[allow(unused)]
+            const CHECK_2: () = if ::static_assert_generic::__private::ENABLED {
+                if !(::static_assert_generic::__private::fits::<u16, _>(N)) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            58usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is too large!");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<N, T>::CHECK_0, Assert::<N, T>::CHECK_1, Assert::<N, T>::CHECK_2)
+    };
+}
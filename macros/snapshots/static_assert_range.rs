@@ -0,0 +1,43 @@
+// static_assert!((N: usize) N in 1..=64)
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED {
+                if !(N >= 1 && N <= 64) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            61usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is not in 1..=64");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: usize>();
+        impl<const N: usize> Assert<N> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(N >= 1 && N <= 64) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            61usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is not in 1..=64");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<N>::CHECK)
+    };
+}
@@ -0,0 +1,43 @@
+// static_assert!((N: u32) N in [1, 2, 4, 8])
+
+fn inline_const() {
+    {
+        const {
+            if ::static_assert_generic::__private::ENABLED {
+                if !(N == 1 || N == 2 || N == 4 || N == 8) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            67usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is not in [1, 2, 4, 8]");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<const N: u32>();
+        impl<const N: u32> Assert<N> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(N == 1 || N == 2 || N == 4 || N == 8) {
+                    {
+                        let message = ::static_assert_generic::__private::Message::<
+                            67usize,
+                        >::new()
+                            .push_str("N = ")
+                            .push_value(N)
+                            .push_str(" is not in [1, 2, 4, 8]");
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<N>::CHECK)
+    };
+}
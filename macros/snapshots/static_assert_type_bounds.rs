@@ -0,0 +1,76 @@
+// static_assert!((T: HasCapacity + Copy) T::CAPACITY > 0)
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const T,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            0usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(T::CAPACITY > 0),
+                                    " (", file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(T::CAPACITY > 0),
+                                " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left = T::CAPACITY;
+                        let right = 0;
+                        let holds = left > right;
+                        (holds, if holds { message } else { message })
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<T: HasCapacity + Copy>(core::marker::PhantomData<T>);
+        impl<T: HasCapacity + Copy> Assert<T> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            0usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(T::CAPACITY > 0),
+                                    " (", file!(), ":", line!(), ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(T::CAPACITY > 0),
+                                " (", file!(), ":", line!(), ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left = T::CAPACITY;
+                        let right = 0;
+                        let holds = left > right;
+                        (holds, if holds { message } else { message })
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<T>::CHECK)
+    };
+}
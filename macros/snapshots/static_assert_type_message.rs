@@ -0,0 +1,28 @@
+// static_assert!((T) std::mem::size_of::<T>() == 4 => "T must be 4 bytes long!")
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const T,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                if !(std::mem::size_of::<T>() == 4) {
+                    panic!("{}", "T must be 4 bytes long!")
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<T>(core::marker::PhantomData<T>);
+        impl<T> Assert<T> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(std::mem::size_of::<T>() == 4) {
+                    panic!("{}", "T must be 4 bytes long!")
+                }
+            };
+        }
+        (Assert::<T>::CHECK)
+    };
+}
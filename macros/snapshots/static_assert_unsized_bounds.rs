@@ -0,0 +1,34 @@
+// static_assert!((U?: std::fmt::Debug) true)
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const U,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                if !(true) {
+                    panic!(
+                        "{}", concat!("static assertion failed: ", stringify!(true),
+                        " (", file!(), ":", line!(), ")")
+                    )
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<U: ?Sized + std::fmt::Debug>(core::marker::PhantomData<U>);
+        impl<U: ?Sized + std::fmt::Debug> Assert<U> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(true) {
+                    panic!(
+                        "{}", concat!("static assertion failed: ", stringify!(true),
+                        " (", file!(), ":", line!(), ")")
+                    )
+                }
+            };
+        }
+        (Assert::<U>::CHECK)
+    };
+}
@@ -0,0 +1,28 @@
+// static_assert!((U?) std::mem::size_of::<&U>() == 8 => "References to U must be thin!")
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const U,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                if !(std::mem::size_of::<&U>() == 8) {
+                    panic!("{}", "References to U must be thin!")
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<U: ?Sized>(core::marker::PhantomData<U>);
+        impl<U: ?Sized> Assert<U> {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                if !(std::mem::size_of::<&U>() == 8) {
+                    panic!("{}", "References to U must be thin!")
+                }
+            };
+        }
+        (Assert::<U>::CHECK)
+    };
+}
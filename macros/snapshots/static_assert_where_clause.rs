@@ -0,0 +1,83 @@
+// static_assert!((T: IntoIterator) where T::Item: HasCapacity; <T::Item as HasCapacity>::CAPACITY > 0)
+
+fn inline_const() {
+    {
+        const {
+            _ = core::marker::PhantomData::<(*const T,)>;
+            if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            0usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(< T::Item as
+                                    HasCapacity > ::CAPACITY > 0), " (", file!(), ":", line!(),
+                                    ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(< T::Item as
+                                HasCapacity > ::CAPACITY > 0), " (", file!(), ":", line!(),
+                                ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left = <T::Item as HasCapacity>::CAPACITY;
+                        let right = 0;
+                        let holds = left > right;
+                        (holds, if holds { message } else { message })
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+        };
+    };
+}
+fn without_inline_const() {
+    _ = {
+        struct Assert<T: IntoIterator>(core::marker::PhantomData<T>);
+        impl<T: IntoIterator> Assert<T>
+        where
+            T::Item: HasCapacity,
+        {
+            #[allow(unused)]
+            const CHECK: () = if ::static_assert_generic::__private::ENABLED {
+                {
+                    let message = ::static_assert_generic::__private::Message::<
+                        {
+                            0usize
+                                + concat!(
+                                    "static assertion failed: ", stringify!(< T::Item as
+                                    HasCapacity > ::CAPACITY > 0), " (", file!(), ":", line!(),
+                                    ")"
+                                )
+                                    .len()
+                        },
+                    >::new()
+                        .push_str(
+                            concat!(
+                                "static assertion failed: ", stringify!(< T::Item as
+                                HasCapacity > ::CAPACITY > 0), " (", file!(), ":", line!(),
+                                ")"
+                            ),
+                        );
+                    let (holds, message) = {
+                        let left = <T::Item as HasCapacity>::CAPACITY;
+                        let right = 0;
+                        let holds = left > right;
+                        (holds, if holds { message } else { message })
+                    };
+                    if !holds {
+                        panic!("{}", message.as_str())
+                    }
+                }
+            };
+        }
+        (Assert::<T>::CHECK)
+    };
+}
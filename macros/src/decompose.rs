@@ -0,0 +1,181 @@
+use crate::message::MAX_VALUE_LEN;
+use crate::{is_const_generic_type, Generic};
+
+/// Whether `op` is one of the comparison operators, whose operands are reported on failure.
+fn is_comparison(op: &syn::BinOp) -> bool {
+    matches!(op, syn::BinOp::Eq(_) | syn::BinOp::Ne(_) | syn::BinOp::Lt(_) | syn::BinOp::Le(_) | syn::BinOp::Gt(_) | syn::BinOp::Ge(_))
+}
+
+fn unparenthesized(expression: &syn::Expr) -> &syn::Expr {
+    match expression {
+        syn::Expr::Paren(paren) => unparenthesized(&paren.expr),
+        syn::Expr::Group(group) => unparenthesized(&group.expr),
+        expression => expression,
+    }
+}
+
+/// Whether `path` names one of the `size_of`/`align_of` functions of `core::mem`, either through `core::mem`, `std::mem` or `mem`,
+/// or by their bare names from the prelude. Functions of the same name elsewhere may return anything else.
+fn is_mem_size_fn(path: &syn::Path) -> bool {
+    let segments: Vec<&syn::PathSegment> = path.segments.iter().collect();
+    let Some((function, modules)) = segments.split_last() else {
+        return false;
+    };
+    if !["size_of", "align_of", "size_of_val", "align_of_val"].iter().any(|name| function.ident == name)
+        || modules.iter().any(|segment| !segment.arguments.is_none()) {
+        return false;
+    }
+    let modules: Vec<String> = modules.iter().map(|segment| segment.ident.to_string()).collect();
+    match modules.as_slice() {
+        [] => path.leading_colon.is_none(),
+        [module] => module == "mem" && path.leading_colon.is_none(),
+        [krate, module] => (krate == "core" || krate == "std") && module == "mem",
+        _ => false,
+    }
+}
+
+/// The type of `expression` if it can be told from its syntax alone, and is one that can be formatted at compile time.
+/// That is the case for declared const generics, suffixed literals, casts and `size_of`/`align_of` calls, along with arithmetic on them.
+fn formattable_type(expression: &syn::Expr, generics: &[Generic]) -> Option<syn::Type> {
+    let ty = match unparenthesized(expression) {
+        syn::Expr::Path(path) if path.qself.is_none() => {
+            let ident = path.path.get_ident()?;
+            generics.iter().find_map(|generic| match generic {
+                Generic::Const(i, t) if i == ident => Some((**t).clone()),
+                _ => None,
+            })?
+        }
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) if !int.suffix().is_empty() => syn::parse_str(int.suffix()).ok()?,
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Bool(_), .. }) => syn::parse_quote! { bool },
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Char(_), .. }) => syn::parse_quote! { char },
+        syn::Expr::Cast(cast) => (*cast.ty).clone(),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_) | syn::UnOp::Not(_), expr, .. }) => formattable_type(expr, generics)?,
+        syn::Expr::Binary(binary) => match binary.op {
+            syn::BinOp::Shl(_) | syn::BinOp::Shr(_) => formattable_type(&binary.left, generics)?,
+            syn::BinOp::Add(_) | syn::BinOp::Sub(_) | syn::BinOp::Mul(_) | syn::BinOp::Div(_) | syn::BinOp::Rem(_)
+            | syn::BinOp::BitAnd(_) | syn::BinOp::BitOr(_) | syn::BinOp::BitXor(_) => {
+                formattable_type(&binary.left, generics).or_else(|| formattable_type(&binary.right, generics))?
+            }
+            _ => return None,
+        },
+        syn::Expr::Call(call) => match &*call.func {
+            syn::Expr::Path(path) if path.qself.is_none() && is_mem_size_fn(&path.path) => syn::parse_quote! { usize },
+            _ => return None,
+        },
+        _ => return None,
+    };
+    is_const_generic_type(&ty).then_some(ty)
+}
+
+/// Generates the checks of a compound expression, which report the clauses that failed.
+struct Decomposer<'a> {
+    generics: &'a [Generic],
+    /// The `stringify!`d clauses that may be pushed to the message, whose lengths add up to its capacity.
+    texts: Vec<proc_macro2::TokenStream>,
+    /// The capacity of the message needed for everything besides `texts`.
+    capacity: usize,
+}
+
+impl Decomposer<'_> {
+    /// The text pushed to the message once `expression` fails, unless it's the whole assertion.
+    fn failed(&mut self, expression: &syn::Expr, root: bool) -> proc_macro2::TokenStream {
+        if root {
+            return proc_macro2::TokenStream::new();
+        }
+        let text = quote::quote! { stringify!(#expression) };
+        self.texts.push(text.clone());
+        self.capacity += "\nfailed: ".len();
+        quote::quote! { .push_str("\nfailed: ").push_str(#text) }
+    }
+
+    /// An expression evaluating `expression` lazily, to whether it holds and the message with the clauses that failed pushed to it.
+    /// Expects the message so far to be in scope as `message`.
+    fn node(&mut self, expression: &syn::Expr, root: bool) -> proc_macro2::TokenStream {
+        match unparenthesized(expression) {
+            syn::Expr::Binary(binary) if matches!(binary.op, syn::BinOp::And(_)) => {
+                let (left, right) = (self.node(&binary.left, false), self.node(&binary.right, false));
+                quote::quote! {{
+                    let (holds, message) = #left;
+                    if holds { #right } else { (false, message) }
+                }}
+            }
+            syn::Expr::Binary(binary) if matches!(binary.op, syn::BinOp::Or(_)) => {
+                let (left, right) = (self.node(&binary.left, false), self.node(&binary.right, false));
+                quote::quote! {{
+                    let original = message;
+                    let (holds, message) = #left;
+                    if holds { (true, original) } else {
+                        let (holds, message) = #right;
+                        if holds { (true, original) } else { (false, message) }
+                    }
+                }}
+            }
+            syn::Expr::Binary(binary) if is_comparison(&binary.op) => self.comparison(expression, binary, false, root),
+            syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Not(_), expr, .. }) => match unparenthesized(expr) {
+                syn::Expr::Binary(binary) if is_comparison(&binary.op) => self.comparison(expression, binary, true, root),
+                _ => self.leaf(expression, root),
+            },
+            _ => self.leaf(expression, root),
+        }
+    }
+
+    /// Evaluates a comparison (negated if `negated`), reporting both operands if their type is known.
+    fn comparison(&mut self, expression: &syn::Expr, binary: &syn::ExprBinary, negated: bool, root: bool) -> proc_macro2::TokenStream {
+        let (left, op, right) = (unparenthesized(&binary.left), &binary.op, unparenthesized(&binary.right));
+        let ty = formattable_type(left, self.generics).or_else(|| formattable_type(right, self.generics));
+        let failed = self.failed(expression, root);
+
+        let (annotation, values) = match ty {
+            Some(ty) => {
+                self.capacity += "\n  left: \n right: ".len() + 2 * MAX_VALUE_LEN;
+                (quote::quote! { : #ty }, quote::quote! { .push_str("\n  left: ").push_value(left).push_str("\n right: ").push_value(right) })
+            }
+            None => (quote::quote! {}, quote::quote! {}),
+        };
+        let holds = match negated {
+            true => quote::quote! { !(left #op right) },
+            false => quote::quote! { left #op right },
+        };
+
+        quote::quote! {{
+            let left #annotation = #left;
+            let right #annotation = #right;
+            let holds = #holds;
+            (holds, if holds { message } else { message #failed #values })
+        }}
+    }
+
+    fn leaf(&mut self, expression: &syn::Expr, root: bool) -> proc_macro2::TokenStream {
+        let failed = self.failed(expression, root);
+        quote::quote! {{
+            let holds: bool = #expression;
+            (holds, if holds { message } else { message #failed })
+        }}
+    }
+}
+
+/// The check of an assertion without a message, if its expression is made up of `&&`, `||`, `!` or comparisons.
+/// Once it fails, the clauses that failed are reported (the first one for `&&`, and every alternative for `||`),
+/// along with the values of the operands of comparisons whose type can be told.
+///
+/// The clauses are evaluated lazily, the same way the expression would be, so that guards like `M != 0 && N % M == 0` still work.
+/// That's why they're evaluated within the body of the check, rather than as associated consts of their own,
+/// since every const a body refers to is evaluated, whether the branch referring to it is taken or not.
+pub fn check(expression: &syn::Expr, generics: &[Generic], span: proc_macro2::Span) -> Option<proc_macro2::TokenStream> {
+    match unparenthesized(expression) {
+        syn::Expr::Binary(binary) if matches!(binary.op, syn::BinOp::And(_) | syn::BinOp::Or(_)) || is_comparison(&binary.op) => {}
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Not(_), .. }) => {}
+        _ => return None,
+    }
+
+    let header = crate::message::failed(expression);
+    let mut decomposer = Decomposer { generics, texts: vec![header.clone()], capacity: 0 };
+    let node = decomposer.node(expression, true);
+    let Decomposer { texts, capacity, .. } = decomposer;
+
+    Some(quote::quote_spanned! {span=> {
+        let message = ::static_assert_generic::__private::Message::<{ #capacity #(+ #texts.len())* }>::new().push_str(#header);
+        let (holds, message) = #node;
+        if !holds { panic!("{}", message.as_str()) }
+    }})
+}
@@ -0,0 +1,226 @@
+use quote::ToTokens;
+use syn::visit_mut::VisitMut;
+
+use crate::message::source;
+use crate::{generics_of, where_predicates_of, AttributeCheck, Generic};
+
+/// Collects every identifier and lifetime in `tokens`, including those that look like `{N}` placeholders inside string literals.
+pub fn mentioned_idents(tokens: proc_macro2::TokenStream, idents: &mut Vec<String>) {
+    let mut lifetime = false;
+    for token in tokens {
+        match token {
+            proc_macro2::TokenTree::Ident(ident) if std::mem::take(&mut lifetime) => idents.push(format!("'{ident}")),
+            proc_macro2::TokenTree::Ident(ident) => idents.push(ident.to_string()),
+            proc_macro2::TokenTree::Group(group) => mentioned_idents(group.stream(), idents),
+            proc_macro2::TokenTree::Literal(literal) => {
+                if let Ok(literal) = syn::parse2::<syn::LitStr>(literal.into_token_stream()) {
+                    idents.extend(literal.value().split(|c: char| !c.is_alphanumeric() && c != '_').map(str::to_string));
+                }
+            }
+            proc_macro2::TokenTree::Punct(punct) => lifetime = punct.as_char() == '\'' && punct.spacing() == proc_macro2::Spacing::Joint,
+        }
+    }
+}
+
+struct Inferrer {
+    /// The generics usable at the current point of the item.
+    generics: Vec<Generic>,
+    /// The where predicates of the item that apply to `generics`, other than those moved onto the generics themselves.
+    predicates: Vec<syn::WherePredicate>,
+}
+
+impl VisitMut for Inferrer {
+    fn visit_item_mut(&mut self, item: &mut syn::Item) {
+        // Generics of outer items can't be used from within nested ones.
+        let (generics, predicates) = match item {
+            syn::Item::Fn(f) => (generics_of(&f.sig.generics), where_predicates_of(&f.sig.generics)),
+            syn::Item::Impl(i) => (generics_of(&i.generics), where_predicates_of(&i.generics)),
+            syn::Item::Trait(t) => (generics_of(&t.generics), where_predicates_of(&t.generics)),
+            _ => (Vec::new(), Vec::new()),
+        };
+        let outer_generics = std::mem::replace(&mut self.generics, generics);
+        let outer_predicates = std::mem::replace(&mut self.predicates, predicates);
+        syn::visit_mut::visit_item_mut(self, item);
+        self.generics = outer_generics;
+        self.predicates = outer_predicates;
+    }
+
+    fn visit_impl_item_fn_mut(&mut self, item: &mut syn::ImplItemFn) {
+        let (len, predicates_len) = (self.generics.len(), self.predicates.len());
+        self.generics.extend(generics_of(&item.sig.generics));
+        self.predicates.extend(where_predicates_of(&item.sig.generics));
+        syn::visit_mut::visit_impl_item_fn_mut(self, item);
+        self.generics.truncate(len);
+        self.predicates.truncate(predicates_len);
+    }
+
+    fn visit_trait_item_fn_mut(&mut self, item: &mut syn::TraitItemFn) {
+        let (len, predicates_len) = (self.generics.len(), self.predicates.len());
+        self.generics.extend(generics_of(&item.sig.generics));
+        self.predicates.extend(where_predicates_of(&item.sig.generics));
+        syn::visit_mut::visit_trait_item_fn_mut(self, item);
+        self.generics.truncate(len);
+        self.predicates.truncate(predicates_len);
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut syn::Macro) {
+        declare_generics(&self.generics, &self.predicates, mac);
+    }
+}
+
+/// Fills in the generics list of a call to one of the macros of this crate, if it was omitted,
+/// with those of `generics` that the call mentions, along with the `predicates` that apply to them.
+fn declare_generics(generics: &[Generic], predicates: &[syn::WherePredicate], mac: &mut syn::Macro) {
+    let Some(name) = mac.path.segments.last().map(|segment| segment.ident.to_string()) else {
+        return;
+    };
+    if crate::generics_omitted(&name, mac.tokens.clone()) == Some(true) {
+        prepend_generics(generics, predicates, mac);
+    }
+}
+
+/// Prepends a generics list to the input of `mac`, declaring those of `generics` that the input mentions,
+/// and a where clause with those of `predicates` that mention the declared generics.
+fn prepend_generics(generics: &[Generic], predicates: &[syn::WherePredicate], mac: &mut syn::Macro) {
+    let mut idents = Vec::new();
+    mentioned_idents(mac.tokens.clone(), &mut idents);
+
+    // Predicates that apply to a mentioned generic, and generics mentioned in the bounds of mentioned generics,
+    // need to be declared as well.
+    let applies = |predicate: &syn::WherePredicate, idents: &[String]| {
+        let mut mentioned = Vec::new();
+        mentioned_idents(predicate.to_token_stream(), &mut mentioned);
+        generics.iter().any(|generic| idents.contains(&generic.name()) && mentioned.contains(&generic.name()))
+    };
+    let mut declared = 0;
+    loop {
+        let mentioned: Vec<&Generic> = generics.iter().filter(|generic| idents.contains(&generic.name())).collect();
+        if mentioned.len() == declared {
+            break;
+        }
+        declared = mentioned.len();
+        for generic in mentioned {
+            mentioned_idents(generic.declaration(), &mut idents);
+        }
+        let applying: Vec<&syn::WherePredicate> = predicates.iter().filter(|predicate| applies(predicate, &idents)).collect();
+        for predicate in applying {
+            mentioned_idents(predicate.to_token_stream(), &mut idents);
+        }
+    }
+
+    let declarations = generics.iter()
+        .filter(|generic| idents.contains(&generic.name()))
+        .map(Generic::declaration);
+    let predicates: Vec<&syn::WherePredicate> = predicates.iter().filter(|predicate| applies(predicate, &idents)).collect();
+
+    // The generics list follows the `#[cfg(...)]` attributes of the assertion, which are `#` tokens followed by brackets.
+    let mut tokens = mac.tokens.clone().into_iter().peekable();
+    let mut attributes = proc_macro2::TokenStream::new();
+    while matches!(tokens.peek(), Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == '#') {
+        attributes.extend(tokens.next());
+        attributes.extend(tokens.next());
+    }
+    // An omitted generics list can still be followed by a where clause, which the predicates are merged into.
+    let where_clause = if predicates.is_empty() {
+        None
+    } else if matches!(tokens.peek(), Some(proc_macro2::TokenTree::Ident(ident)) if ident == "where") {
+        tokens.next();
+        Some(quote::quote! { where #(#predicates,)* })
+    } else {
+        Some(quote::quote! { where #(#predicates),*; })
+    };
+    let tokens: proc_macro2::TokenStream = tokens.collect();
+    mac.tokens = quote::quote! { #attributes (#(#declarations),*) #where_clause #tokens };
+}
+
+pub fn static_asserts(attr: proc_macro2::TokenStream, item: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    if !attr.is_empty() {
+        return Err(syn::Error::new_spanned(attr, "`#[static_asserts]` doesn't take any arguments."));
+    }
+
+    let mut item: syn::Item = syn::parse2(item)?;
+    if !matches!(item, syn::Item::Fn(_) | syn::Item::Impl(_)) {
+        return Err(syn::Error::new(proc_macro2::Span::call_site(), "`#[static_asserts]` can only be used on `fn` items and `impl` blocks."));
+    }
+
+    Inferrer { generics: Vec::new(), predicates: Vec::new() }.visit_item_mut(&mut item);
+    Ok(item.into_token_stream())
+}
+
+/// Inserts an assertion of every requirement at the start of `block`, declaring the `generics` it mentions
+/// and the `predicates` that apply to them.
+/// Requirements never have a generics list of their own, so one is always declared, even if the condition starts with parentheses.
+fn insert_requirements(block: &mut syn::Block, generics: &[Generic], predicates: &[syn::WherePredicate], requirements: &[AttributeCheck]) {
+    let assertions = requirements.iter().map(|AttributeCheck { condition, message }| {
+        let message = message.as_ref().map(|message| quote::quote! { => #message });
+        let mut mac: syn::Macro = syn::parse_quote! { ::static_assert_generic::static_assert!(#condition #message) };
+        prepend_generics(generics, predicates, &mut mac);
+        syn::Stmt::Macro(syn::StmtMacro { attrs: Vec::new(), mac, semi_token: Some(Default::default()) })
+    });
+    block.stmts.splice(0..0, assertions);
+}
+
+/// The "Compile-time requirements" section of the docs of an item, listing every requirement along with its message.
+fn requirements_section(requirements: &[AttributeCheck]) -> Vec<syn::Attribute> {
+    let items = requirements.iter().map(|AttributeCheck { condition, message }| {
+        let description = message.as_ref()
+            .and_then(|message| syn::parse2::<syn::LitStr>(message.clone().into_iter().take(1).collect()).ok())
+            .map(|literal| format!(": {}", literal.value()))
+            .unwrap_or_default();
+        format!(" - `{}`{description}", source(condition))
+    });
+    ["", " # Compile-time requirements", ""].into_iter().map(str::to_string).chain(items)
+        .map(|line| syn::parse_quote! { #[doc = #line] })
+        .collect()
+}
+
+/// Whether `path` names this crate's `#[requires]`, either imported or through the crate's own path.
+/// Attributes of the same name from other crates are left in place.
+fn is_requires(path: &syn::Path) -> bool {
+    let segments: Vec<String> = path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+    match segments.as_slice() {
+        [name] => name == "requires" && path.leading_colon.is_none(),
+        [krate, name] => krate == "static_assert_generic" && name == "requires",
+        _ => false,
+    }
+}
+
+pub fn requires(attr: proc_macro2::TokenStream, item: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let mut item: syn::Item = syn::parse2(item)?;
+    // The bodies to insert the assertions into, along with the generics usable in them and the where predicates that apply.
+    type Body<'a> = (&'a mut syn::Block, Vec<Generic>, Vec<syn::WherePredicate>);
+    let (attrs, bodies): (_, Vec<Body>) = match &mut item {
+        syn::Item::Fn(f) => (&mut f.attrs, vec![(&mut *f.block, generics_of(&f.sig.generics), where_predicates_of(&f.sig.generics))]),
+        syn::Item::Impl(i) => {
+            let (generics, predicates) = (generics_of(&i.generics), where_predicates_of(&i.generics));
+            let bodies = i.items.iter_mut().filter_map(|item| match item {
+                syn::ImplItem::Fn(f) => Some((
+                    &mut f.block,
+                    generics.iter().cloned().chain(generics_of(&f.sig.generics)).collect(),
+                    predicates.iter().cloned().chain(where_predicates_of(&f.sig.generics)).collect(),
+                )),
+                _ => None,
+            }).collect();
+            (&mut i.attrs, bodies)
+        }
+        _ => return Err(syn::Error::new(proc_macro2::Span::call_site(), "`#[requires]` can only be used on `fn` items and `impl` blocks.")),
+    };
+
+    // The other `#[requires]` attributes of the item are handled along with this one, so that they share a single section of the docs.
+    let mut requirements = vec![syn::parse2::<AttributeCheck>(attr)?];
+    let mut rest = Vec::new();
+    for attribute in attrs.drain(..) {
+        if is_requires(attribute.path()) {
+            requirements.push(attribute.parse_args()?);
+        } else {
+            rest.push(attribute);
+        }
+    }
+    rest.extend(requirements_section(&requirements));
+    *attrs = rest;
+
+    for (block, generics, predicates) in bodies {
+        insert_requirements(block, &generics, &predicates, &requirements);
+    }
+    Ok(item.into_token_stream())
+}
@@ -0,0 +1,1527 @@
+/*!
+Procedural macros of the [`static_assert_generic`](https://docs.rs/static_assert_generic) crate, which re-exports them.
+Depend on that crate instead, since the macros expand to paths into it.
+*/
+
+mod decompose;
+mod infer;
+mod message;
+mod predicate;
+#[cfg(test)]
+mod tests;
+mod traits;
+
+use message::Message;
+use predicate::Condition;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+
+/// Whether the compiler supports inline `const { }` blocks, which assertions expand to instead of an `Assert` struct.
+/// Set by the build script, unless the `Assert` struct is forced with `--cfg static_assert_generic_no_inline_const` (to test it on newer compilers).
+const INLINE_CONST: bool = cfg!(all(inline_const, not(static_assert_generic_no_inline_const)));
+
+/// Whether the compiler supports `#[diagnostic::on_unimplemented]`, which gives failed trait assertions their messages.
+/// Set by the build script.
+const DIAGNOSTIC_NAMESPACE: bool = cfg!(diagnostic_namespace);
+
+type Bounds = syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]>;
+
+#[derive(Clone)]
+enum Generic {
+    Type(syn::Ident, Bounds),
+    UnsizedType(syn::Ident, Bounds),
+    Const(syn::Ident, Box<syn::Type>),
+    Lifetime(syn::Lifetime),
+}
+
+impl Generic {
+    pub fn definition(&self) -> proc_macro2::TokenStream {
+        match self {
+            Generic::Type(i, b) if b.is_empty() => quote::quote! { #i, },
+            Generic::Type(i, b) => quote::quote! { #i: #b, },
+            Generic::UnsizedType(i, b) if b.is_empty() => quote::quote! { #i: ?Sized, },
+            Generic::UnsizedType(i, b) => quote::quote! { #i: ?Sized + #b, },
+            Generic::Const(i, t) => quote::quote! { const #i: #t, },
+            Generic::Lifetime(l) => quote::quote! { #l, },
+        }
+    }
+
+    pub fn placement(&self) -> proc_macro2::TokenStream {
+        match self {
+            Generic::Type(i, _b) => quote::quote! { #i, },
+            Generic::UnsizedType(i, _b) => quote::quote! { #i, },
+            Generic::Const(i, _t) => quote::quote! { #i, },
+            Generic::Lifetime(l) => quote::quote! { #l, },
+        }
+    }
+
+    pub fn placement_type(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Generic::Type(i, _b) => Some(quote::quote! { #i }),
+            Generic::UnsizedType(i, _b) => Some(quote::quote! { #i }),
+            Generic::Const(_i, _t) => None,
+            Generic::Lifetime(_l) => None,
+        }
+    }
+
+    /// The generic as written in a generics list, the inverse of parsing it.
+    pub fn declaration(&self) -> proc_macro2::TokenStream {
+        match self {
+            Generic::Type(i, b) if b.is_empty() => quote::quote! { #i },
+            Generic::Type(i, b) => quote::quote! { #i: #b },
+            Generic::UnsizedType(i, b) if b.is_empty() => quote::quote! { #i? },
+            Generic::UnsizedType(i, b) => quote::quote! { #i?: #b },
+            Generic::Const(i, t) if is_const_generic_type(t) => quote::quote! { #i: #t },
+            Generic::Const(i, t) => quote::quote! { const #i: #t },
+            Generic::Lifetime(l) => quote::quote! { #l },
+        }
+    }
+
+    /// The name of the generic, including the leading `'` for lifetimes.
+    pub fn name(&self) -> String {
+        match self {
+            Generic::Type(i, _) | Generic::UnsizedType(i, _) | Generic::Const(i, _) => i.to_string(),
+            Generic::Lifetime(l) => l.to_string(),
+        }
+    }
+
+    pub fn const_ident(&self) -> Option<&syn::Ident> {
+        match self {
+            Generic::Const(i, _t) => Some(i),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `ty` is one of the types const generics can have (integers, `bool` and `char`).
+/// Anything else following a `:` in a generics list is a list of trait bounds, unless the generic is declared with `const`.
+fn is_const_generic_type(ty: &syn::Type) -> bool {
+    const TYPES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize",
+        "i8", "i16", "i32", "i64", "i128", "isize",
+        "bool", "char",
+    ];
+
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return false;
+    };
+    let segments: Vec<String> = path.segments.iter()
+        .filter(|segment| segment.arguments.is_none())
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    if segments.len() != path.segments.len() {
+        return false;
+    }
+
+    match segments.as_slice() {
+        [ty] => TYPES.contains(&ty.as_str()),
+        [krate, primitive, ty] => (krate == "core" || krate == "std") && primitive == "primitive" && TYPES.contains(&ty.as_str()),
+        _ => false,
+    }
+}
+
+fn is_maybe_sized(bound: &syn::TypeParamBound) -> bool {
+    matches!(bound, syn::TypeParamBound::Trait(syn::TraitBound { modifier: syn::TraitBoundModifier::Maybe(_), .. }))
+}
+
+/// Converts the generics of an item (or an angle-bracketed generics list) into the ones that can be declared in a generics list.
+/// Bounds from the where clause that apply directly to a type generic are moved onto the generic itself.
+fn generics_of(generics: &syn::Generics) -> Vec<Generic> {
+    generics.params.iter().map(|param| match param {
+        syn::GenericParam::Type(t) => {
+            let where_bounds = generics.where_clause.iter().flat_map(|w| &w.predicates).filter_map(|predicate| match predicate {
+                syn::WherePredicate::Type(p) if matches!(&p.bounded_ty, syn::Type::Path(path) if path.qself.is_none() && path.path.is_ident(&t.ident)) => {
+                    Some(p.bounds.iter().cloned().map(|mut bound| {
+                        if let syn::TypeParamBound::Trait(trait_bound) = &mut bound {
+                            trait_bound.lifetimes = trait_bound.lifetimes.take().or_else(|| p.lifetimes.clone());
+                        }
+                        bound
+                    }))
+                }
+                _ => None,
+            }).flatten();
+
+            let all_bounds: Vec<syn::TypeParamBound> = t.bounds.iter().cloned().chain(where_bounds).collect();
+            let bounds = all_bounds.iter().filter(|bound| !is_maybe_sized(bound)).cloned().collect();
+            if all_bounds.iter().any(is_maybe_sized) {
+                Generic::UnsizedType(t.ident.clone(), bounds)
+            } else {
+                Generic::Type(t.ident.clone(), bounds)
+            }
+        }
+        syn::GenericParam::Const(c) => Generic::Const(c.ident.clone(), Box::new(c.ty.clone())),
+        syn::GenericParam::Lifetime(l) => Generic::Lifetime(l.lifetime.clone()),
+    }).collect()
+}
+
+/// The predicates of the where clause of `generics` that [`generics_of`] doesn't move onto a generic,
+/// such as bounds of associated types or of lifetimes.
+fn where_predicates_of(generics: &syn::Generics) -> Vec<syn::WherePredicate> {
+    generics.where_clause.iter().flat_map(|w| &w.predicates).filter(|predicate| match predicate {
+        syn::WherePredicate::Type(p) => !generics.type_params().any(|t| {
+            matches!(&p.bounded_ty, syn::Type::Path(path) if path.qself.is_none() && path.path.is_ident(&t.ident))
+        }),
+        _ => true,
+    }).cloned().collect()
+}
+
+impl syn::parse::Parse for Generic {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Lifetime) {
+            return Ok(Generic::Lifetime(input.parse()?));
+        }
+
+        // Const generics whose type can't be told from its name (such as a type alias) are declared like in Rust.
+        if input.parse::<Option<syn::Token![const]>>()?.is_some() {
+            let ident = input.parse()?;
+            input.parse::<syn::Token![:]>()?;
+            return Ok(Generic::Const(ident, Box::new(input.parse()?)));
+        }
+
+        let ident: syn::Ident = input.parse()?;
+        let is_unsized = input.parse::<syn::Token![?]>().is_ok();
+        if input.parse::<syn::Token![:]>().is_err() {
+            return Ok(if is_unsized { Generic::UnsizedType(ident, Bounds::new()) } else { Generic::Type(ident, Bounds::new()) });
+        }
+
+        if let Ok(qm) = input.parse::<syn::Token![?]>() {
+            return Err(syn::Error::new(qm.span, format!("Syntax error, if you want to make the type unsized do {ident}? instead of {ident}: ?Sized.")))
+        }
+
+        let fork = input.fork();
+        if !is_unsized && fork.parse::<syn::Type>().is_ok_and(|ty| is_const_generic_type(&ty)) && (fork.is_empty() || fork.peek(syn::Token![,]) || fork.peek(syn::Token![=>])) {
+            return Ok(Generic::Const(ident, Box::new(input.parse()?)));
+        }
+
+        let bounds = Bounds::parse_separated_nonempty(input).map_err(|err| syn::Error::new(err.span(), format!(
+            "{err}. Only integers, `bool` and `char` are taken as the type of a const generic, declare others with `const {ident}: Type`."
+        )))?;
+        if let Some(bound) = bounds.iter().find(|bound| is_maybe_sized(bound)) {
+            return Err(syn::Error::new_spanned(bound, format!("Syntax error, if you want to make the type unsized do {ident}? instead of {ident}: ?Sized.")))
+        }
+
+        Ok(if is_unsized { Generic::UnsizedType(ident, bounds) } else { Generic::Type(ident, bounds) })
+    }
+}
+
+/// The bounds needed by the references to declared type generics in `tokens` for them to be well-formed,
+/// such as `T: 'a` for `&'a T`, where both `T` and `'a` are among the `generics`.
+fn reference_outlives(tokens: proc_macro2::TokenStream, generics: &[Generic]) -> Vec<syn::WherePredicate> {
+    let reference = |input: syn::parse::ParseStream| -> syn::Result<(syn::Lifetime, syn::Type)> {
+        let lifetime = input.parse()?;
+        input.parse::<Option<syn::Token![mut]>>()?;
+        let ty = input.parse()?;
+        input.parse::<proc_macro2::TokenStream>()?;
+        Ok((lifetime, ty))
+    };
+
+    let tokens: Vec<proc_macro2::TokenTree> = tokens.into_iter().collect();
+    let mut predicates: Vec<syn::WherePredicate> = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let found = match token {
+            proc_macro2::TokenTree::Group(group) => reference_outlives(group.stream(), generics),
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '&' => {
+                let Ok((lifetime, ty)) = syn::parse::Parser::parse2(reference, tokens[i + 1..].iter().cloned().collect()) else { continue };
+                if !generics.iter().any(|generic| matches!(generic, Generic::Lifetime(l) if *l == lifetime)) {
+                    continue;
+                }
+                let mut idents = Vec::new();
+                infer::mentioned_idents(ty.into_token_stream(), &mut idents);
+                generics.iter()
+                    .filter(|generic| match generic {
+                        Generic::Type(_, bounds) | Generic::UnsizedType(_, bounds) => idents.contains(&generic.name())
+                            && !bounds.iter().any(|bound| matches!(bound, syn::TypeParamBound::Lifetime(l) if *l == lifetime)),
+                        _ => false,
+                    })
+                    .filter_map(Generic::placement_type)
+                    .map(|ty| syn::parse_quote! { #ty: #lifetime })
+                    .collect()
+            }
+            _ => continue,
+        };
+        for predicate in found {
+            if !predicates.iter().any(|p| p.to_token_stream().to_string() == predicate.to_token_stream().to_string()) {
+                predicates.push(predicate);
+            }
+        }
+    }
+    predicates
+}
+
+/// Parses a generics list, either with this crate's own syntax in parentheses, or verbatim Rust syntax in angle brackets.
+/// Bounds of lifetimes in angle brackets are moved to `where_clause`, along with the bounds implied by references in the rest of `input`
+/// (see [`reference_outlives`]), since types aren't otherwise assumed to outlive the lifetimes.
+fn parse_generics(input: syn::parse::ParseStream, where_clause: &mut Option<syn::WhereClause>) -> syn::Result<Vec<Generic>> {
+    if input.peek(syn::Token![<]) {
+        let generics: syn::Generics = input.parse()?;
+        let lifetime_bounds: Vec<syn::WherePredicate> = generics.lifetimes()
+            .filter(|l| !l.bounds.is_empty())
+            .map(|l| {
+                let (lifetime, bounds) = (&l.lifetime, &l.bounds);
+                syn::parse_quote! { #lifetime: #bounds }
+            })
+            .collect();
+        let params = generics_of(&generics);
+        let rest: proc_macro2::TokenStream = input.fork().parse()?;
+        let outlives = reference_outlives(rest, &params);
+        if !lifetime_bounds.is_empty() || !outlives.is_empty() {
+            where_clause.get_or_insert_with(|| syn::parse_quote! { where }).predicates.extend(lifetime_bounds.into_iter().chain(outlives));
+        }
+        return Ok(params);
+    }
+
+    let generics_buf;
+    syn::parenthesized!(generics_buf in input);
+    Ok(generics_buf.parse_terminated(<Generic as syn::parse::Parse>::parse, syn::Token![,])?.into_iter().collect())
+}
+
+/// The generics declared by an assertion.
+struct Generics {
+    params: Vec<Generic>,
+    where_clause: Option<syn::WhereClause>,
+    /// Whether the generics list was written out, rather than omitted.
+    explicit: bool,
+    /// Whether the declared types are implied to outlive the declared lifetimes,
+    /// which is the case unless the generics were written verbatim in angle brackets.
+    implied_outlives: bool,
+}
+
+/// Parses an optional `where` clause, terminated by a `;`.
+fn parse_where_clause(input: syn::parse::ParseStream) -> syn::Result<Option<syn::WhereClause>> {
+    if !input.peek(syn::Token![where]) {
+        return Ok(None);
+    }
+    let where_clause = input.parse()?;
+    input.parse::<syn::Token![;]>()?;
+    Ok(Some(where_clause))
+}
+
+fn merge_where_clauses(where_clause: Option<syn::WhereClause>, other: Option<syn::WhereClause>) -> Option<syn::WhereClause> {
+    match (where_clause, other) {
+        (Some(mut where_clause), Some(other)) => {
+            where_clause.predicates.extend(other.predicates);
+            Some(where_clause)
+        }
+        (where_clause, other) => where_clause.or(other),
+    }
+}
+
+/// Parses an optional generics list and `where` clause followed by `rest`.
+/// Leading parentheses (or angle brackets) that don't form a generics list followed by `rest` are considered part of `rest` instead.
+fn parse_generics_then<T>(input: syn::parse::ParseStream, rest: impl Fn(syn::parse::ParseStream) -> syn::Result<T>) -> syn::Result<(Generics, T)> {
+    let implicit = |input: syn::parse::ParseStream| -> syn::Result<(Generics, T)> {
+        let where_clause = parse_where_clause(input)?;
+        Ok((Generics { params: Vec::new(), where_clause, explicit: false, implied_outlives: true }, rest(input)?))
+    };
+    let explicit = |input: syn::parse::ParseStream| -> syn::Result<(Generics, T)> {
+        let implied_outlives = !input.peek(syn::Token![<]);
+        let mut lifetime_bounds = None;
+        let params = parse_generics(input, &mut lifetime_bounds)?;
+        let where_clause = merge_where_clauses(parse_where_clause(input)?, lifetime_bounds);
+        Ok((Generics { params, where_clause, explicit: true, implied_outlives }, rest(input)?))
+    };
+
+    if !input.peek(syn::token::Paren) && !input.peek(syn::Token![<]) {
+        return implicit(input);
+    }
+
+    let fork = input.fork();
+    let err = match explicit(&fork) {
+        Ok(_) if fork.is_empty() => {
+            reject_ambiguous_generics(input, implicit)?;
+            return explicit(input);
+        }
+        Ok(_) => fork.error("unexpected token"),
+        Err(err) => err,
+    };
+
+    let fork = input.fork();
+    match implicit(&fork) {
+        Ok(_) if fork.is_empty() => implicit(input),
+        _ => Err(err),
+    }
+}
+
+/// Errors if a generics list in parentheses is followed by an operator that could also continue an expression starting with it,
+/// such as `(N) - 1 < 0`, which would otherwise be taken as the generics list `(N)` and the condition `-1 < 0`.
+fn reject_ambiguous_generics<T>(input: syn::parse::ParseStream, implicit: impl Fn(syn::parse::ParseStream) -> syn::Result<T>) -> syn::Result<()> {
+    let Some((proc_macro2::TokenTree::Group(list), rest)) = input.cursor().token_tree() else {
+        return Ok(());
+    };
+    let Some((proc_macro2::TokenTree::Punct(op), _)) = rest.token_tree() else {
+        return Ok(());
+    };
+    if list.delimiter() != proc_macro2::Delimiter::Parenthesis || !matches!(op.as_char(), '-' | '*' | '!' | '&') {
+        return Ok(());
+    }
+
+    let fork = input.fork();
+    if implicit(&fork).is_err() || !fork.is_empty() {
+        return Ok(());
+    }
+    Err(syn::Error::new(list.span(), format!(
+        "`{}` followed by `{}` could be either a generics list or the start of the condition. \
+        Wrap the condition in parentheses if it starts with `{0}`, or write the generics list in angle brackets otherwise.",
+        message::source(list), op.as_char(),
+    )))
+}
+
+fn parse_message(input: syn::parse::ParseStream) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    Ok(if input.parse::<syn::Token![=>]>().is_ok() { Some(input.parse()?) } else { None })
+}
+
+/// Parses the `#[cfg(...)]` attributes preceding an assertion, returning their predicates.
+fn parse_cfgs(input: syn::parse::ParseStream) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    input.call(syn::Attribute::parse_outer)?.into_iter().map(|attribute| {
+        if !attribute.path().is_ident("cfg") {
+            return Err(syn::Error::new_spanned(attribute, "Only `#[cfg(...)]` attributes can be put on assertions."));
+        }
+        Ok(attribute.meta.require_list()?.tokens.clone())
+    }).collect()
+}
+
+/// Wraps a `check` so that it's only evaluated if assertions aren't turned off, and every one of the `cfgs` predicates holds.
+/// Otherwise, it's still type-checked.
+fn gated(check: proc_macro2::TokenStream, cfgs: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+    quote::quote! {
+        if ::static_assert_generic::__private::ENABLED #(&& cfg!(#cfgs))* { #check }
+    }
+}
+
+/// The types to put in `PhantomData`s so that the generated struct uses all of its generics.\
+/// With `implied_outlives`, lifetimes are used as `&'a T` for every declared type `T`, so that the types are implied to outlive them.
+fn phantom_types(generics: &[Generic], implied_outlives: bool) -> Vec<proc_macro2::TokenStream> {
+    let types: Vec<proc_macro2::TokenStream> = generics.iter().filter_map(Generic::placement_type).collect();
+    let lifetimes = generics.iter().filter_map(|generic| match generic {
+        Generic::Lifetime(l) if types.is_empty() || !implied_outlives => Some(vec![quote::quote! { &#l () }]),
+        Generic::Lifetime(l) => Some(types.iter().map(|t| quote::quote! { &#l #t }).collect()),
+        _ => None,
+    }).flatten();
+
+    types.iter().cloned().chain(lifetimes).collect()
+}
+
+/// Generates a struct named `name` holding the declared generics, with `items` in its impl.
+/// Returns the struct and impl, along with the path to the struct with the generics placed in it.
+fn struct_with_generics(name: &str, generics: &Generics, items: proc_macro2::TokenStream) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let name = syn::Ident::new(name, proc_macro2::Span::call_site());
+    let where_clause = &generics.where_clause;
+    let implied_outlives = generics.implied_outlives;
+
+    // Lifetimes need to be declared before any other generics.
+    let mut generics = generics.params.clone();
+    generics.sort_by_key(|generic| !matches!(generic, Generic::Lifetime(_)));
+
+    let generic_definitions: proc_macro2::TokenStream = generics.iter().map(Generic::definition).collect();
+    let generic_placement: proc_macro2::TokenStream = generics.iter().map(Generic::placement).collect();
+    let generic_placement_types = phantom_types(&generics, implied_outlives);
+
+    (
+        quote::quote! {
+            struct #name<#generic_definitions>(#(core::marker::PhantomData<#generic_placement_types>),*);
+            impl<#generic_definitions> #name<#generic_placement> #where_clause {
+                #items
+            }
+        },
+        quote::quote! { #name::<#generic_placement> },
+    )
+}
+
+/// Generates the `Assert` struct holding the declared generics, with a constant evaluating each of the `checks`.\
+/// The constant is named `CHECK` if there's only one check, and `CHECK_0`, `CHECK_1`, ... otherwise.
+/// With `inline_const`, the checks are evaluated in an inline `const` block instead, which can use the generics of the outer item directly.
+fn assert_with_generics(generics: &Generics, checks: &[proc_macro2::TokenStream], inline_const: bool) -> proc_macro2::TokenStream {
+    if inline_const {
+        let uses = uses_of_types(generics);
+        return quote::quote! {
+            {
+                #(const { #uses #checks };)*
+            }
+        };
+    }
+
+    let (items, path, names) = assert_items(generics, checks);
+
+    quote::quote! {
+        _ = {
+            #items
+            (#(#path::#names),*)
+        }
+    }
+}
+
+/// A statement using the declared type generics, for inline `const` blocks that don't otherwise mention them,
+/// so that functions whose type generics are only used by assertions aren't taken to have unused ones.
+fn uses_of_types(generics: &Generics) -> Option<proc_macro2::TokenStream> {
+    let types: Vec<proc_macro2::TokenStream> = generics.params.iter().filter_map(Generic::placement_type).collect();
+    (!types.is_empty()).then(|| quote::quote! { _ = core::marker::PhantomData::<(#(*const #types,)*)>; })
+}
+
+/// The items behind [`assert_with_generics`], along with the path to the `Assert` struct and the names of its constants.
+fn assert_items(generics: &Generics, checks: &[proc_macro2::TokenStream]) -> (proc_macro2::TokenStream, proc_macro2::TokenStream, Vec<syn::Ident>) {
+    let names: Vec<syn::Ident> = match checks.len() {
+        1 => vec![quote::format_ident!("CHECK")],
+        _ => (0..checks.len()).map(|i| quote::format_ident!("CHECK_{i}")).collect(),
+    };
+
+    let (assert, path) = struct_with_generics("Assert", generics, quote::quote! {
+        #(
+            #[allow(unused)]
+            const #names: () = #checks;
+        )*
+    });
+
+    (assert, path, names)
+}
+
+struct StaticAssertInput {
+    /// The predicates of the `#[cfg(...)]` attributes of the assertion.
+    cfgs: Vec<proc_macro2::TokenStream>,
+    generics: Generics,
+    /// The proposition following `->`, whose `Checked` evidence the assertion evaluates to.
+    evidence: Option<syn::Type>,
+    /// The asserted expressions along with their messages, more than one if written as a block.
+    checks: Vec<(Condition, Option<proc_macro2::TokenStream>)>,
+}
+
+/// Parses a single `expr => message` check, whose message is a single expression,
+/// optionally followed by `help:` and `note:` lines.
+fn parse_check(input: syn::parse::ParseStream) -> syn::Result<(Condition, Option<proc_macro2::TokenStream>)> {
+    let condition = input.parse()?;
+    if input.parse::<syn::Token![=>]>().is_err() {
+        return Ok((condition, None));
+    }
+    let mut message = input.parse::<syn::Expr>()?.into_token_stream();
+    while message::peek_annotation(input) {
+        let comma = input.parse::<syn::Token![,]>()?;
+        let kind = input.parse::<syn::Ident>()?;
+        let colon = input.parse::<syn::Token![:]>()?;
+        let line = input.parse::<syn::LitStr>()?;
+        message.extend(quote::quote! { #comma #kind #colon #line });
+    }
+    Ok((condition, Some(message)))
+}
+
+/// Parses comma-separated checks.
+fn parse_checks(input: syn::parse::ParseStream) -> syn::Result<Vec<(Condition, Option<proc_macro2::TokenStream>)>> {
+    let checks = input.parse_terminated(parse_check, syn::Token![,])?;
+    if checks.is_empty() {
+        return Err(input.error("Expected at least one check."));
+    }
+    Ok(checks.into_iter().collect())
+}
+
+/// Parses a `{ expr => message, expr, ... }` block of checks.
+fn parse_check_block(input: syn::parse::ParseStream) -> syn::Result<Vec<(Condition, Option<proc_macro2::TokenStream>)>> {
+    let checks_buf;
+    syn::braced!(checks_buf in input);
+    parse_checks(&checks_buf)
+}
+
+/// Parses either a block of checks, or a single expression followed by an optional message taking up the rest of the input.
+fn parse_assertion(input: syn::parse::ParseStream) -> syn::Result<Vec<(Condition, Option<proc_macro2::TokenStream>)>> {
+    // A block that isn't followed by anything could also be a block expression, which is only considered if it isn't a valid block of checks.
+    if input.peek(syn::token::Brace) {
+        let fork = input.fork();
+        if parse_check_block(&fork).is_ok() && fork.is_empty() {
+            return parse_check_block(input);
+        }
+    }
+    Ok(vec![(input.parse()?, parse_message(input)?)])
+}
+
+impl syn::parse::Parse for StaticAssertInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let cfgs = parse_cfgs(input)?;
+        let (generics, (evidence, checks)) = parse_generics_then(input, |input| {
+            let evidence = match input.parse::<Option<syn::Token![->]>>()? {
+                // Gated assertions aren't checked when their predicates don't hold, so they can't vouch for anything.
+                Some(arrow) if !cfgs.is_empty() => return Err(syn::Error::new(arrow.spans[0],
+                    "Assertions with `#[cfg(...)]` attributes can't produce evidence with `->`, since they aren't always checked.")),
+                Some(_) => Some(input.parse()?),
+                None => None,
+            };
+            Ok((evidence, parse_assertion(input)?))
+        })?;
+        Ok(StaticAssertInput { cfgs, generics, evidence, checks })
+    }
+}
+
+/// The main use-case for this crate.\
+/// Macro for asserting statements at compile-time, with the possibility of passing in generics as well.
+/// Refer to to the crate-level documentation for more information.
+#[proc_macro]
+pub fn static_assert(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_assert(input.into(), INLINE_CONST))
+}
+
+fn expand_static_assert(input: proc_macro2::TokenStream, inline_const: bool) -> syn::Result<proc_macro2::TokenStream> {
+    let StaticAssertInput { cfgs, generics, evidence, checks } = syn::parse2(input)?;
+    let checks = check_expressions(&generics, &checks, &cfgs, inline_const)?;
+    let assertion = assert_with_generics(&generics, &checks, inline_const);
+    Ok(match evidence {
+        Some(proposition) => quote::quote! {
+            {
+                #assertion;
+                ::static_assert_generic::__private::checked::<#proposition>()
+            }
+        },
+        None => assertion,
+    })
+}
+
+/// Converts the result of a `proc_macro2`-level expansion into the output of a macro.
+fn expand(expansion: syn::Result<proc_macro2::TokenStream>) -> proc_macro::TokenStream {
+    expansion.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Converts the checks of an assertion into the `if !(expr) { panic!(..) }` expressions evaluated by the `Assert` struct.
+fn check_expressions(
+    generics: &Generics,
+    checks: &[(Condition, Option<proc_macro2::TokenStream>)],
+    cfgs: &[proc_macro2::TokenStream],
+    inline_const: bool,
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let messages = checks.iter()
+        .map(|(_, message)| Message::new(message.clone(), &generics.params, inline_const))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // With multiple checks, each one is reported at its own expression.
+    checks.iter().zip(messages).map(|((condition, _), message)| {
+        let span = if checks.len() == 1 { proc_macro2::Span::call_site() } else { condition.span() };
+        check_expression(condition, message, &generics.params, cfgs, span)
+    }).collect()
+}
+
+/// The `if !(expr) { panic!(..) }` expression checking a single `condition`, reported at `span`, and gated by `cfgs`.
+/// Without a message, compound expressions report the clauses that failed, and predicates report the value of their generic.
+fn check_expression(
+    condition: &Condition,
+    message: Message,
+    generics: &[Generic],
+    cfgs: &[proc_macro2::TokenStream],
+    span: proc_macro2::Span,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (expression, message) = match condition {
+        Condition::Expression(expression) => (expression.clone(), message),
+        Condition::Predicate(predicate) => predicate.check(message, generics)?,
+    };
+    let decomposed = match message {
+        Message::Verbatim(None) => decompose::check(&expression, generics, span),
+        _ => None,
+    };
+    let check = decomposed.unwrap_or_else(|| {
+        let panic = message.or_failed(&expression).panic(span);
+        quote::quote! { if !(#expression) { #panic } }
+    });
+    Ok(gated(check, cfgs))
+}
+
+struct StaticAssertCmpInput {
+    cfgs: Vec<proc_macro2::TokenStream>,
+    generics: Generics,
+    left: syn::Expr,
+    right: syn::Expr,
+    message: Option<proc_macro2::TokenStream>,
+}
+
+impl syn::parse::Parse for StaticAssertCmpInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let cfgs = parse_cfgs(input)?;
+        let (generics, (left, right, message)) = parse_generics_then(input, |input| {
+            let left = input.parse()?;
+            input.parse::<syn::Token![,]>()?;
+            Ok((left, input.parse()?, parse_message(input)?))
+        })?;
+        Ok(StaticAssertCmpInput { cfgs, generics, left, right, message })
+    }
+}
+
+fn expand_static_assert_cmp(input: proc_macro2::TokenStream, op: proc_macro2::TokenStream, inline_const: bool) -> syn::Result<proc_macro2::TokenStream> {
+
+    let StaticAssertCmpInput { cfgs, generics, left, right, message } = syn::parse2(input)?;
+
+    let message = Message::comparison(&op.to_string(), Message::new(message, &generics.params, inline_const)?, quote::quote! { *left }, quote::quote! { *right })?;
+    let panic = message.panic(proc_macro2::Span::call_site());
+
+    let check = gated(quote::quote! {
+        match (&(#left), &(#right)) {
+            (left, right) => if !(*left #op *right) { #panic }
+        }
+    }, &cfgs);
+    Ok(assert_with_generics(&generics, &[check], inline_const))
+}
+
+/// Asserts that two expressions are equal at compile-time, reporting both of their values on failure (like `assert_eq!`).
+///
+/// Takes the same generics list as [`static_assert!`], followed by the two operands separated by a comma:
+///
+/// ```ignore
+/// fn foo<const N: usize, T>() {
+///     static_assert_eq!((N: usize, T) N, std::mem::size_of::<T>() / 2 => "N must be half the size of T!");
+/// }
+///
+/// foo::<12, [u8; 32]>();
+///
+/// // the evaluated program panicked at 'assertion `left == right` failed: N must be half the size of T!
+/// //   left: 12
+/// //  right: 16'
+/// ```
+///
+/// The operands need to be integers, `bool`s or `char`s so that they can be formatted at compile time.
+#[proc_macro]
+pub fn static_assert_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_assert_cmp(input.into(), quote::quote! { == }, INLINE_CONST))
+}
+
+/// Asserts that two expressions are not equal at compile-time, reporting both of their values on failure.
+/// Refer to [`static_assert_eq!`] for more information.
+#[proc_macro]
+pub fn static_assert_ne(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_assert_cmp(input.into(), quote::quote! { != }, INLINE_CONST))
+}
+
+/// Asserts that the left expression is less than the right one at compile-time, reporting both of their values on failure.
+/// Refer to [`static_assert_eq!`] for more information.
+#[proc_macro]
+pub fn static_assert_lt(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_assert_cmp(input.into(), quote::quote! { < }, INLINE_CONST))
+}
+
+/// Asserts that the left expression is less than or equal to the right one at compile-time, reporting both of their values on failure.
+/// Refer to [`static_assert_eq!`] for more information.
+#[proc_macro]
+pub fn static_assert_le(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_assert_cmp(input.into(), quote::quote! { <= }, INLINE_CONST))
+}
+
+/// Asserts that the left expression is greater than the right one at compile-time, reporting both of their values on failure.
+/// Refer to [`static_assert_eq!`] for more information.
+#[proc_macro]
+pub fn static_assert_gt(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_assert_cmp(input.into(), quote::quote! { > }, INLINE_CONST))
+}
+
+/// Asserts that the left expression is greater than or equal to the right one at compile-time, reporting both of their values on failure.
+/// Refer to [`static_assert_eq!`] for more information.
+#[proc_macro]
+pub fn static_assert_ge(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_assert_cmp(input.into(), quote::quote! { >= }, INLINE_CONST))
+}
+
+/// Item-level assertions for module scope, with no generics.\
+/// Each assertion expands to its own `const _: () = ...;` item, keeping its own message and span:
+///
+/// ```ignore
+/// static_assert_items! {
+///     std::mem::size_of::<usize>() == 8 => "Only 64-bit platforms are supported!",
+///     cfg!(target_endian = "little") => "Only little endian platforms are supported!",
+///     std::mem::align_of::<Header>() <= 16,
+/// }
+/// ```
+///
+/// This is equivalent to writing `const _: () = static_assert!(() ...);` for every assertion.
+#[proc_macro]
+pub fn static_assert_items(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_assert_items(input.into(), INLINE_CONST))
+}
+
+fn expand_static_assert_items(input: proc_macro2::TokenStream, inline_const: bool) -> syn::Result<proc_macro2::TokenStream> {
+    let parser = |input: syn::parse::ParseStream| input.parse_terminated(|input| Ok((parse_cfgs(input)?, parse_check(input)?)), syn::Token![,]);
+    let checks = syn::parse::Parser::parse2(parser, input)?;
+
+    checks.into_iter().map(|(cfgs, (condition, message))| {
+        let span = condition.span();
+        let check = check_expression(&condition, Message::new(message, &[], inline_const)?, &[], &cfgs, span)?;
+        Ok(quote::quote_spanned! {span=>
+            const _: () = #check;
+        })
+    }).collect()
+}
+
+struct StaticAssertInstantiationsInput {
+    cfgs: Vec<proc_macro2::TokenStream>,
+    generics: Generics,
+    /// The generic arguments of every listed instantiation, in the order the generics were declared in.
+    instantiations: Vec<Vec<proc_macro2::TokenStream>>,
+    checks: Vec<(Condition, Option<proc_macro2::TokenStream>)>,
+}
+
+/// Parses a single generic argument for `generic`, wrapping const expressions in braces.
+fn parse_generic_argument(input: syn::parse::ParseStream, generic: &Generic) -> syn::Result<proc_macro2::TokenStream> {
+    Ok(match generic {
+        Generic::Const(..) => {
+            let expression: syn::Expr = input.parse()?;
+            quote::quote! { { #expression } }
+        }
+        Generic::Type(..) | Generic::UnsizedType(..) => input.parse::<syn::Type>()?.into_token_stream(),
+        Generic::Lifetime(_) => input.parse::<syn::Lifetime>()?.into_token_stream(),
+    })
+}
+
+/// Parses the generic arguments of one instantiation, which are in parentheses unless there's a single generic.
+fn parse_instantiation(input: syn::parse::ParseStream, generics: &[Generic]) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    if let [generic] = generics {
+        return Ok(vec![parse_generic_argument(input, generic)?]);
+    }
+
+    let arguments_buf;
+    syn::parenthesized!(arguments_buf in input);
+    let arguments = generics.iter().enumerate().map(|(i, generic)| {
+        if i > 0 {
+            arguments_buf.parse::<syn::Token![,]>()?;
+        }
+        parse_generic_argument(&arguments_buf, generic)
+    }).collect::<syn::Result<Vec<_>>>()?;
+    arguments_buf.parse::<Option<syn::Token![,]>>()?;
+    if !arguments_buf.is_empty() {
+        return Err(arguments_buf.error(format!("Expected {} generic arguments, one for each declared generic.", generics.len())));
+    }
+    Ok(arguments)
+}
+
+impl syn::parse::Parse for StaticAssertInstantiationsInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let cfgs = parse_cfgs(input)?;
+        input.parse::<syn::Token![for]>()?;
+        let implied_outlives = !input.peek(syn::Token![<]);
+        let generics_span = input.span();
+        let mut lifetime_bounds = None;
+        let params = parse_generics(input, &mut lifetime_bounds)?;
+        if params.is_empty() {
+            return Err(syn::Error::new(generics_span, "Expected at least one generic, use `static_assert_items!` for assertions without generics."));
+        }
+
+        input.parse::<syn::Token![in]>()?;
+        let instantiations_buf;
+        syn::bracketed!(instantiations_buf in input);
+        let mut instantiations = Vec::new();
+        while !instantiations_buf.is_empty() {
+            instantiations.push(parse_instantiation(&instantiations_buf, &params)?);
+            if instantiations_buf.parse::<Option<syn::Token![,]>>()?.is_none() && !instantiations_buf.is_empty() {
+                return Err(instantiations_buf.error("expected `,`"));
+            }
+        }
+
+        let where_clause = merge_where_clauses(parse_where_clause(input)?, lifetime_bounds);
+        let generics = Generics { params, where_clause, explicit: true, implied_outlives };
+        Ok(StaticAssertInstantiationsInput { cfgs, generics, instantiations, checks: parse_assertion(input)? })
+    }
+}
+
+/// Checks an assertion for a list of concrete instantiations of its generics, as item-level constants.\
+/// Unlike `static_assert!`, whose generic constants are only evaluated once a full build instantiates them,
+/// these are evaluated by `cargo check` (and rust-analyzer) as well.
+///
+/// The generics list follows `for`, and the instantiations to check follow `in`.
+/// With more than one generic, each instantiation is a tuple of generic arguments in the order the generics were declared in:
+///
+/// ```ignore
+/// static_assert_instantiations!(for (N: usize) in [1, 2, 64] N != 0 => "N must be a non-zero value!");
+///
+/// static_assert_instantiations!(for (N: usize, T) in [(4, u32), (2, u64)] N * std::mem::size_of::<T>() <= 16);
+///
+/// static_assert_instantiations!(for (N: usize) in [0, 1, 64] N != 0 => "N must be a non-zero value!");
+/// // error[E0080]: evaluation of `_::Assert::<0>::CHECK` failed
+/// //  | static_assert_instantiations!(for (N: usize) in [0, 1, 64] N != 0 => "N must be a non-zero value!");
+/// //  | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'N must be a non-zero value!'
+/// ```
+///
+/// A `where` clause and a block of checks can be used just like with `static_assert!`.
+/// The assertion can be placed at module scope or in the body of a function, next to the `static_assert!` it mirrors.
+#[proc_macro]
+pub fn static_assert_instantiations(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_assert_instantiations(input.into()))
+}
+
+/// The instantiations are always checked through the `Assert` struct, since they're outside of any generic item.
+fn expand_static_assert_instantiations(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+
+    let StaticAssertInstantiationsInput { cfgs, generics, instantiations, checks } = syn::parse2(input)?;
+
+    let checks = check_expressions(&generics, &checks, &cfgs, false)?;
+    let (items, _, names) = assert_items(&generics, &checks);
+
+    let evaluations = instantiations.iter().flat_map(|arguments| {
+        let path = instantiation_path("Assert", &generics.params, arguments);
+        names.iter().map(move |name| quote::quote! { const _: () = #path::#name; })
+    });
+
+    Ok(quote::quote! {
+        const _: () = {
+            #items
+            #(#evaluations)*
+        };
+    })
+}
+
+/// The path to the struct `name` generated by [`struct_with_generics`], with the `arguments` of an instantiation placed in it.
+fn instantiation_path(name: &str, generics: &[Generic], arguments: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+    let name = syn::Ident::new(name, proc_macro2::Span::call_site());
+    // The arguments need to be in the same order as the generics of the struct, which has its lifetimes first.
+    let mut arguments: Vec<(&Generic, &proc_macro2::TokenStream)> = generics.iter().zip(arguments).collect();
+    arguments.sort_by_key(|(generic, _)| !matches!(generic, Generic::Lifetime(_)));
+    let arguments = arguments.into_iter().map(|(_, argument)| argument);
+    quote::quote! { #name::<#(#arguments),*> }
+}
+
+struct StaticWarnInput {
+    cfgs: Vec<proc_macro2::TokenStream>,
+    generics: Generics,
+    /// The instantiations to check when written in the `for (N: usize) in [..]` form, which is the only one that can have generics.
+    instantiations: Vec<Vec<proc_macro2::TokenStream>>,
+    checks: Vec<(Condition, Option<proc_macro2::TokenStream>)>,
+}
+
+impl syn::parse::Parse for StaticWarnInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        parse_cfgs(&fork)?;
+        if fork.peek(syn::Token![for]) {
+            let StaticAssertInstantiationsInput { cfgs, generics, instantiations, checks } = input.parse()?;
+            return Ok(StaticWarnInput { cfgs, generics, instantiations, checks });
+        }
+
+        let span = fork.span();
+        let StaticAssertInput { cfgs, generics, evidence, checks } = input.parse()?;
+        if let Some(proposition) = evidence {
+            return Err(syn::Error::new_spanned(proposition, "Warnings don't stop the build, so they can't produce evidence that their checks passed."));
+        }
+        if !generics.params.is_empty() || generics.where_clause.is_some() {
+            return Err(syn::Error::new(span, "Warnings are emitted before generics are instantiated, so they can't depend on generics. \
+                List the instantiations to check instead, such as `static_warn!(for (N: usize) in [4, 8, 32] ...)`."));
+        }
+        Ok(StaticWarnInput { cfgs, generics, instantiations: vec![Vec::new()], checks })
+    }
+}
+
+/// Emits a compiler warning instead of an error if a statement doesn't hold, for constraints that are only advisory.\
+/// The build still succeeds, and the warning shows up under `cargo check` as well:
+///
+/// ```ignore
+/// static_warn!(() std::mem::size_of::<Header>() <= 64 => "Header no longer fits in a cache line.");
+/// // warning: use of deprecated associated constant `_::StaticWarning::<true, 0>::WARNING`: Header no longer fits in a cache line.
+/// ```
+///
+/// Since warnings are emitted before generics are instantiated, they can't depend on the generics of the surrounding item.
+/// Instead, generics are declared following `for`, along with the instantiations to check following `in`, like with [`static_assert_instantiations!`].
+/// Placeholders in the message are replaced by the generic arguments of the instantiation:
+///
+/// ```ignore
+/// static_warn!(for (N: usize) in [8, 16, 32] N >= 16 => "N = {N} is slow, and will be phased out.", help: "use N >= 16");
+/// // warning: use of deprecated associated constant `_::StaticWarning::<true, 0>::WARNING`: N = 8 is slow, and will be phased out.
+/// //          help: use N >= 16
+/// ```
+///
+/// Unlike in the messages of `static_assert!`, placeholders aren't evaluated: an argument is written as it's given,
+/// so `in [4 * 2]` makes `{N}` read `4 * 2` rather than `8`.
+///
+/// A block of checks and the predicates of `static_assert!` can be used as well.
+/// Messages need to be string literals, and default to naming the condition that failed.
+/// Like every other assertion, warnings are turned off by the `off` feature and `--cfg static_assert_generic_off`.
+/// Like `static_assert!`, it expands to a `()` expression, so it needs to be written as `const _: () = static_warn!(...);` at module scope.
+#[proc_macro]
+pub fn static_warn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_warn(input.into()))
+}
+
+/// Every failed check references a deprecated constant of the `StaticWarning` struct, whose deprecation note is its message.
+/// Which constant is referenced is decided by evaluating the check as a const generic argument, before generics are instantiated.
+fn expand_static_warn(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+
+    let StaticWarnInput { cfgs, generics, instantiations, checks } = syn::parse2(input)?;
+
+    let checks = checks.iter().map(|(condition, message)| {
+        let message = Message::new(message.clone(), &generics.params, false)?;
+        let span = if checks.len() == 1 { proc_macro2::Span::call_site() } else { condition.span() };
+        let (expression, message) = match condition {
+            Condition::Expression(expression) => (expression.clone(), message),
+            Condition::Predicate(predicate) => predicate.check(message, &generics.params)?,
+        };
+        if let Message::Verbatim(Some(tokens)) = &message {
+            return Err(syn::Error::new_spanned(tokens, "The message of a warning needs to be a string literal."));
+        }
+        Ok((expression, message, span))
+    }).collect::<syn::Result<Vec<_>>>()?;
+
+    let names: Vec<syn::Ident> = (0..checks.len()).map(|i| quote::format_ident!("CHECK_{i}")).collect();
+    let assert = match generics.params.is_empty() {
+        true => None,
+        false => {
+            let expressions = checks.iter().map(|(expression, ..)| expression);
+            Some(struct_with_generics("Assert", &generics, quote::quote! {
+                #(
+                    #[allow(unused)]
+                    const #names: bool = #expressions;
+                )*
+            }).0)
+        }
+    };
+
+    let mut notes = Vec::new();
+    let mut references = Vec::new();
+    for arguments in &instantiations {
+        let path = instantiation_path("Assert", &generics.params, arguments);
+        for ((expression, message, span), name) in checks.iter().zip(&names) {
+            let condition = match &assert {
+                Some(_) => quote::quote! { #path::#name },
+                None => quote::quote! { (#expression) },
+            };
+            // Values can only be the declared const generics, whose arguments are wrapped in braces.
+            // The note is a string literal, so they're written as they're given rather than evaluated.
+            let note = message.text(|value| {
+                let i = generics.params.iter().position(|generic| generic.name() == value.to_string()).unwrap();
+                match arguments[i].clone().into_iter().next() {
+                    Some(proc_macro2::TokenTree::Group(group)) => group.stream().to_string(),
+                    _ => arguments[i].to_string(),
+                }
+            }).unwrap_or_else(|| format!("static warning condition failed: {}", expression.to_token_stream()));
+
+            let index = notes.len();
+            notes.push(note);
+            references.push(quote::quote_spanned! {*span=>
+                #(#[cfg(#cfgs)])*
+                const _: () = StaticWarning::<{ ::static_assert_generic::__private::ENABLED && !#condition }, #index>::WARNING;
+            });
+        }
+    }
+    let indices = 0..notes.len();
+
+    Ok(quote::quote! {
+        {
+            #assert
+            struct StaticWarning<const FAILED: bool, const INDEX: usize>;
+            impl<const INDEX: usize> StaticWarning<false, INDEX> {
+                #[allow(unused)]
+                const WARNING: () = ();
+            }
+            #(
+                impl StaticWarning<true, #indices> {
+                    #[allow(unused)]
+                    #[deprecated(note = #notes)]
+                    const WARNING: () = ();
+                }
+            )*
+            #(#references)*
+        }
+    })
+}
+
+/// Whether the generics list of a call to the macro of this crate named `name` was omitted.
+/// `None` if the macro doesn't take a generics list or its input doesn't parse.
+fn generics_omitted(name: &str, tokens: proc_macro2::TokenStream) -> Option<bool> {
+    match name {
+        "static_assert" => syn::parse2::<StaticAssertInput>(tokens).ok().map(|input| !input.generics.explicit),
+        "static_assert_eq" | "static_assert_ne" | "static_assert_lt" | "static_assert_le" | "static_assert_gt" | "static_assert_ge" => {
+            syn::parse2::<StaticAssertCmpInput>(tokens).ok().map(|input| !input.generics.explicit)
+        }
+        "generic_const" => syn::parse2::<GenericConstInput>(tokens).ok().map(|input| !input.generics.explicit),
+        _ => None,
+    }
+}
+
+/// Attribute for `fn` items and `impl` blocks that fills in the generics list of the assertions inside them.\
+/// The generics are taken from the signature of the item, and only the ones each assertion actually mentions are declared,
+/// along with their types, bounds and the where predicates that apply to them:
+///
+/// ```ignore
+/// #[static_asserts]
+/// fn foo<const N: usize, const M: usize, T: ?Sized>() {
+///     static_assert!(N > M => "N must be greater than M!");
+///     // expands to static_assert!((N: usize, M: usize) N > M => "N must be greater than M!");
+///
+///     static_assert_eq!(N, 4);
+///     // expands to static_assert_eq!((N: usize) N, 4);
+/// }
+///
+/// #[static_asserts]
+/// impl<const N: usize> Buffer<N> {
+///     fn get<const I: usize>(&self) -> u8 {
+///         static_assert!(I < N => "Index out of bounds!");
+///         // expands to static_assert!((N: usize, I: usize) I < N => "Index out of bounds!");
+///         self.0[I]
+///     }
+/// }
+/// ```
+///
+/// Assertions that already have a generics list are left as is.
+/// To make sure an expression starting with parentheses isn't mistaken for a generics list, an empty list (`()`) can be written out,
+/// or the whole condition wrapped in parentheses if it uses generics.
+/// Parentheses followed by `-`, `*`, `!` or `&` that could be either are rejected, such as in `static_assert!((N) - 1 < M)`.
+#[proc_macro_attribute]
+pub fn static_asserts(attr: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match infer::static_asserts(attr.into(), item.clone().into()) {
+        Ok(item) => item.into(),
+        Err(err) => {
+            let err = err.into_compile_error();
+            let item = proc_macro2::TokenStream::from(item);
+            quote::quote! { #err #item }.into()
+        }
+    }
+}
+
+/// Attribute for `fn` items and `impl` blocks that states a requirement on their generics, both as a static assertion and in their docs.\
+/// It takes a check written like those of [`static_assert!`], optionally followed by a message,
+/// and inserts the assertion at the start of the body of the function (or of every method of the `impl` block),
+/// with the generics list filled in from the signature like [`#[static_asserts]`](macro@static_asserts) does:
+///
+/// ```ignore
+/// #[requires(N != 0, "N must be non-zero")]
+/// #[requires(N is power_of_two)]
+/// pub fn split<const N: usize>(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+///     // static_assert!((N: usize) N != 0 => "N must be non-zero");
+///     // static_assert!((N: usize) N is power_of_two);
+///     data.chunks(N)
+/// }
+/// ```
+///
+/// A "Compile-time requirements" section listing every requirement of the item is added to its docs:
+///
+/// ```text
+/// # Compile-time requirements
+///
+///  - `N != 0`: N must be non-zero
+///  - `N is power_of_two`
+/// ```
+#[proc_macro_attribute]
+pub fn requires(attr: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match infer::requires(attr.into(), item.clone().into()) {
+        Ok(item) => item.into(),
+        Err(err) => {
+            let err = err.into_compile_error();
+            let item = proc_macro2::TokenStream::from(item);
+            quote::quote! { #err #item }.into()
+        }
+    }
+}
+
+
+
+
+
+struct GenericConstInput {
+    generics: Generics,
+    ty: syn::Type,
+    expression: syn::Expr,
+    predicate: Option<(Condition, Option<proc_macro2::TokenStream>)>,
+}
+
+impl syn::parse::Parse for GenericConstInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let (generics, (ty, expression, predicate)) = parse_generics_then(input, |input| {
+            input.parse::<syn::Token![->]>()?;
+            let ty = input.parse()?;
+            let expression = input.parse()?;
+            let predicate = if input.parse::<syn::Token![if]>().is_ok() { Some((input.parse()?, parse_message(input)?)) } else { None };
+            Ok((ty, expression, predicate))
+        })?;
+        Ok(GenericConstInput { generics, ty, expression, predicate })
+    }
+}
+
+/// Evaluates an expression involving generics at compile-time, for every instantiation.\
+/// Takes the same generics list as [`static_assert!`], followed by `->` and the type of the value:
+///
+/// ```ignore
+/// fn foo<const N: usize>() {
+///     let doubled = generic_const!((N: usize) -> usize N * 2);
+/// }
+/// ```
+///
+/// The value can optionally be paired with a predicate that it's only valid for, checked the same way `static_assert!` would:
+///
+/// ```ignore
+/// fn foo<const N: usize, T>() {
+///     let len = generic_const!((N: usize, T) -> usize N * std::mem::size_of::<T>() if N <= 64 => "N = {N} is too large!");
+/// }
+/// ```
+///
+/// Like any other constant, it can be used in const contexts that don't depend on the generics of the outer item.
+/// However, since generic parameters may not be used in const operations on stable Rust,
+/// it can't be used for things like array lengths in a generic function (`[u8; generic_const!((N: usize) -> usize N * 2)]`).
+#[proc_macro]
+pub fn generic_const(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_generic_const(input.into(), INLINE_CONST))
+}
+
+fn expand_generic_const(input: proc_macro2::TokenStream, inline_const: bool) -> syn::Result<proc_macro2::TokenStream> {
+
+    let GenericConstInput { generics, ty, expression, predicate } = syn::parse2(input)?;
+
+    let check = match predicate {
+        Some((predicate, message)) => {
+            let message = Message::new(message, &generics.params, inline_const)?;
+            Some(check_expression(&predicate, message, &generics.params, &[], proc_macro2::Span::call_site())?)
+        }
+        None => None,
+    };
+
+    if inline_const {
+        let uses = uses_of_types(&generics);
+        return Ok(quote::quote! {
+            const {
+                #uses
+                #check
+                let value: #ty = #expression;
+                value
+            }
+        });
+    }
+
+    let (generic_const, path) = struct_with_generics("GenericConst", &generics, quote::quote! {
+        #[allow(unused)]
+        const VALUE: #ty = {
+            #check
+            #expression
+        };
+    });
+
+    Ok(quote::quote! {
+        {
+            #generic_const
+            #path::VALUE
+        }
+    })
+}
+
+
+
+/// Experimental macro that forces variables of a certain type to not have their destructor run.\
+/// However, it also has some serious drawbacks, meaning that you should likely refrain from using it in any serious project.
+/// Its primary use case is being able to drop objects that require updating other structures:
+/// 
+/// ```ignore
+/// impl<T> Foo<T> {
+///     explicitly_drop!(T => "MyStruct must be dropped explicitly!");
+/// }
+/// ```
+/// 
+/// To prevent constant evaluation from always happening, the functionality is dependant on at least one generic (be it type or const)
+/// from the type it's implemented in, so if the type in question doesn't have that the macro won't work:
+/// 
+/// ```ignore
+/// impl<T> Drop for Foo<T> {
+///     explicitly_drop!(T => "Dependant on type generic");
+/// }
+/// 
+/// impl<T: ?Sized> Drop for Foo<T> {
+///     explicitly_drop!(T? => "If the type is unsized it needs special syntax");
+/// }
+/// 
+/// impl<const C: u8> Drop for Foo<{C}> {
+///     explicitly_drop!(C: u8 => "Dependant on const generic (specifying the type is needed)");
+/// }
+/// 
+/// impl<const C: u8, const D: u16, T, U, V> Drop for Foo<{C}, {D}, T, U, V> {
+///     explicitly_drop!(C: u8 => "Just one is needed, even if the type has more.");
+/// }
+/// ```
+/// 
+/// Using a lifetime as the generic doesn't work, since constants that only depend on lifetimes are always evaluated.
+/// 
+/// # Example:
+/// 
+/// Consider a situation like this, where multiple allocators may be present at a time:\
+/// 
+/// ```ignore
+/// struct Allocator {
+///     ...
+/// }
+///
+/// impl Allocator {
+///     pub fn alloc<T>(&mut self) -> Allocation<T> {
+///         todo!()
+///     }
+///
+///     pub fn free<T>(&mut self, allocation: Allocation<T>) {
+///         // ...
+///     }
+/// }
+///
+/// struct Allocation<T> {
+///     ptr_to_allocation: *const T
+/// }
+/// ```
+/// 
+/// Idealy, when the `Allocation` runs out of scope, it would be freed by the same `Allocator` that allocated it.
+/// However, implementing such `Drop` functionality would require the allocation to also hold some kind of reference to said `Allocator`.
+/// This would double its size and may become an issue if `Allocation` appears often.
+/// Still, if the programmer forgets to free the `Allocation` a memory leak would take place.
+///
+/// Using `explicitly_drop!` would give a compile-time error if `Allocation`'s `drop` method appears anywhere in the code:
+/// 
+/// ```ignore
+/// impl<T> Drop for Allocation<T> {
+///     explicitly_drop!(T => "Allocation must be freed explicitly!");
+/// }
+/// ```
+/// 
+/// The `free` method would have to make sure that `Allocation`'s `drop` method doesn't appear either.
+/// 
+/// ```ignore
+/// pub fn free<T>(&mut self, allocation: Allocation<T>) {
+///     let allocation = std::mem::ManuallyDrop::new(allocation);
+///     // ...
+/// }
+/// ```
+/// 
+/// Now if someone forgets to `free` an `Allocation`, the compiler will give an error 
+/// (just like `static_assert!`, this isn't caught by `cargo check`, and a full build is needed instead):
+/// 
+/// ```ignore
+/// fn foo(allocator: Allocator) {
+///     let allocation: Allocation::<Whatever> = allocator.alloc();
+///     // ... allocation is not freed
+///     // allocation's drop method appears
+/// }
+/// 
+/// foo(my_allocator);
+/// 
+/// // error[E0080]: evaluation of `<Allocation<T> as std::ops::Drop>::drop::Assert::<Whatever>::MANUAL_DROP` failed
+/// //    |
+/// //    |         explicitly_drop!(T => "Allocation must be freed explicitly!");
+/// //    |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'Allocation must be freed explicitly!'
+/// //    |
+/// //
+/// // note: the above error was encountered while instantiating `fn <Allocation<Whatever> as std::ops::Drop>::drop`
+/// //    |
+/// //    | pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
+/// //    | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// ```
+/// 
+/// `free` (or prevent its `drop` method from appearing in any way) and the error dissapears:
+/// 
+/// ```ignore
+/// fn foo(allocator: Allocator) {
+///     let allocation: Allocation::<Whatever> = allocator.alloc();
+///     // ... do something
+///     allocator.free(allocation);
+/// }
+/// 
+/// foo(my_allocator);
+/// 
+/// // compiles just fine
+/// ```
+/// 
+/// # Drawbacks
+/// 
+/// The drop method might appear even when it doesn't seem it should at first glance.
+/// For example, if a panic ever occours, all variables in scope, including those that need to be `explicitly_drop`ped, have their `drop` 
+/// method run, so even if the panic never occours at runtime, the simple appearance of `drop` will still cause a compile-time error:
+/// 
+/// ```ignore
+/// fn bar(allocator: Allocator) {
+///     let allocation: Allocation::<Whatever> = allocator.alloc();
+///     if rand::random::<usize>() == 27 {
+///         panic!();
+///     }
+///     allocator.free(allocation);
+/// }
+/// 
+/// foo(my_allocator);
+/// 
+/// // ... 'Allocation must be freed explicitly!' ...
+/// ```
+/// 
+/// A huge amount of functionality in rust can result in panics. 
+/// Even if explicit `panic!`, `todo!`, or `unwrap`s are avoided, these operations, and many more, can also panic:
+/// - Indexing into a container without bounds checking.
+/// - Basically every unchecked heap allocation.
+/// - Any mathematical operation that can over/underflow (on a debug build).
+/// - Possible division or modulo operation by 0.
+/// 
+/// This method also assumes that rust optimises out, and as such doesn't attempt to evaluate 
+/// constants if the method they are in isn't use, which might not even always be the case.
+#[proc_macro]
+pub fn explicitly_drop(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_explicitly_drop(input.into(), INLINE_CONST))
+}
+
+fn expand_explicitly_drop(input: proc_macro2::TokenStream, inline_const: bool) -> syn::Result<proc_macro2::TokenStream> {
+    struct ExplicitlyDropInput {
+        generic: Generic,
+        message: Option<proc_macro2::TokenStream>,
+    }
+    
+    impl syn::parse::Parse for ExplicitlyDropInput {
+        fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+            Ok(ExplicitlyDropInput {
+                generic: match input.parse()? {
+                    Generic::Lifetime(l) => return Err(syn::Error::new(l.span(),
+                        "`explicitly_drop!` can't depend on a lifetime, since constants that only depend on lifetimes are always evaluated. Use a type or const generic instead.")),
+                    generic => generic,
+                },
+                message: if input.parse::<syn::Token![=>]>().is_ok() { Some(input.parse()?) } else { None },
+            })
+        }
+    }
+
+    let ExplicitlyDropInput { generic, message } = syn::parse2(input)?;
+
+    let message = Message::new(message, std::slice::from_ref(&generic), inline_const)?;
+    let panic = message.panic(proc_macro2::Span::call_site());
+
+    if inline_const {
+        return Ok(quote::quote! {
+            fn drop(&mut self) {
+                const {
+                    #panic
+                }
+            }
+        });
+    }
+
+    let generic_definition = generic.definition();
+    let generic_placement = generic.placement();
+    let phantomdatas = generic.placement_type()
+        .map(|x| quote::quote! { (core::marker::PhantomData<#x>) });
+
+    Ok(quote::quote! {
+        fn drop(&mut self) {
+            _ = {
+                struct Assert<#generic_definition>#phantomdatas;
+                impl<#generic_definition> Assert<#generic_placement> {
+                    const MANUAL_DROP: () = #panic;
+                }
+                Assert::<#generic_placement>::MANUAL_DROP
+            };
+        }
+    })
+}
+
+/// The arguments of an `#[invariant(...)]` or `#[requires(...)]` attribute: a check, optionally followed by a message.
+struct AttributeCheck {
+    condition: Condition,
+    message: Option<proc_macro2::TokenStream>,
+}
+
+impl syn::parse::Parse for AttributeCheck {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let condition = input.parse()?;
+        let message = match input.parse::<Option<syn::Token![,]>>()? {
+            Some(_) if !input.is_empty() => Some(input.parse()?),
+            _ => None,
+        };
+        Ok(AttributeCheck { condition, message })
+    }
+}
+
+/// Declares invariants of a type's generics once, instead of repeating `static_assert!` in every constructor.\
+/// Every `#[invariant(...)]` attribute takes a check written like those of [`static_assert!`], optionally followed by a message,
+/// and can use the generics of the type without declaring them:
+///
+/// ```ignore
+/// #[derive(StaticInvariants)]
+/// #[invariant(N > 0 && N <= 4096, "capacity out of range")]
+/// #[invariant(N is power_of_two, "N = {N} must be a power of two", help: "round N up")]
+/// struct RingBuf<T, const N: usize> {
+///     buf: [Option<T>; N],
+///     head: usize,
+/// }
+///
+/// impl<T, const N: usize> RingBuf<T, N> {
+///     pub fn new() -> Self {
+///         Self::__check();
+///         RingBuf { buf: [const { None }; N], head: 0 }
+///     }
+/// }
+///
+/// RingBuf::<u8, 100>::new();
+/// // error[E0080]: evaluation of `RingBuf::<u8, 100>::__INVARIANTS` failed
+/// // the evaluated program panicked at 'N = 100 must be a power of two
+/// // help: round N up'
+/// ```
+///
+/// The invariants are checked by a hidden associated constant, which is evaluated by calling the generated `Self::__check()`.
+/// Since a derive can't change the code of the type, `Self::__check()` needs to be called from its constructors,
+/// so that any instantiation that gets built is checked. It's a `const fn`, so it can be called from `const` constructors as well.
+#[proc_macro_derive(StaticInvariants, attributes(invariant))]
+pub fn static_invariants(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(expand_static_invariants(input.into()))
+}
+
+fn expand_static_invariants(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+
+    let input: syn::DeriveInput = syn::parse2(input)?;
+    let generics = generics_of(&input.generics);
+
+    let checks = input.attrs.iter().filter(|attribute| attribute.path().is_ident("invariant")).map(|attribute| {
+        let AttributeCheck { condition, message } = attribute.parse_args()?;
+        let message = Message::new(message, &generics, false)?;
+        check_expression(&condition, message, &generics, &[], attribute.path().span())
+    }).collect::<syn::Result<Vec<_>>>()?;
+    if checks.is_empty() {
+        return Err(syn::Error::new(input.ident.span(), "Expected at least one `#[invariant(...)]` attribute."));
+    }
+
+    let (name, vis, where_clause) = (&input.ident, &input.vis, &input.generics.where_clause);
+    let generic_definitions: proc_macro2::TokenStream = generics.iter().map(Generic::definition).collect();
+    let generic_placement: proc_macro2::TokenStream = generics.iter().map(Generic::placement).collect();
+
+    Ok(quote::quote! {
+        impl<#generic_definitions> #name<#generic_placement> #where_clause {
+            #[doc(hidden)]
+            #[allow(unused)]
+            const __INVARIANTS: () = { #(#checks)* };
+
+            /// Checks the invariants of this instantiation of the type, failing to compile if one of them doesn't hold.
+            #[doc(hidden)]
+            #[allow(unused)]
+            #vis const fn __check() {
+                Self::__INVARIANTS
+            }
+        }
+    })
+}
+
+/// Asserts that a type implements a set of traits, failing to compile with an error naming the type and the trait otherwise.\
+/// Takes the same generics list as [`static_assert!`], followed by the type and its traits, like a `where` clause would:
+///
+/// ```ignore
+/// assert_impl!(Handle: Send + Sync + 'static);
+/// assert_impl!((T: Send) Vec<T>: Send);
+/// assert_impl!(std::rc::Rc<u8>: Send);
+/// // error[E0277]: `std::rc::Rc<u8>` doesn't implement `Send`
+/// //  |
+/// //  | assert_impl!(std::rc::Rc<u8>: Send);
+/// //  |              ^^^^^^^^^^^^^^^ asserted to implement `Send`
+/// ```
+///
+/// A generic type is checked for every instantiation of the declared generics at once, rather than for the ones that get built,
+/// so `assert_impl!((T) Vec<T>: Send)` fails, since `T` could be any type.
+/// Unlike the other assertions, it's checked while type-checking, so it fails `cargo check` as well.
+///
+/// The message can be replaced by following the traits with `=> "message"`.
+/// Lifetime bounds such as `'static` are checked too, but are reported by the borrow checker instead.
+/// The messages need Rust 1.78 or newer (`#[diagnostic::on_unimplemented]`),
+/// older compilers report the failure as an unsatisfied `RequirementN` trait bound instead.
+#[proc_macro]
+pub fn assert_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(traits::expand_assert_impl(input.into()))
+}
+
+/// Asserts that a type doesn't implement a trait, such as guards that need to stay on one thread not implementing `Send`.\
+/// Takes the same generics list as [`static_assert!`], followed by the type and the trait:
+///
+/// ```ignore
+/// assert_not_impl!(MutexGuard<'static, u8>: Send);
+/// assert_not_impl!((T) Guard<T>: any(Send, Sync, Clone) => "guards must stay where they were created");
+/// assert_not_impl!(Cell<u8>: all(Copy, Sync));
+///
+/// assert_not_impl!(u8: any(Send, Copy));
+/// // error[E0277]: `u8` implements `Send`
+/// //  |
+/// //  | assert_not_impl!(u8: any(Send, Copy));
+/// //  |                  ^^ asserted not to implement `Send`
+/// ```
+///
+/// `any(...)` asserts that none of its traits are implemented, and `all(...)` that at least one of them isn't, and they can be nested.
+/// The message can be replaced by following the traits with `=> "message"`.
+///
+/// Like [`assert_impl!`], it's checked while type-checking. For a generic type, a trait only counts as implemented
+/// if it's implemented for every instantiation of the declared generics, such as `Vec<T>` implementing `Send` given `(T: Send)`.
+/// As with [`assert_impl!`], the messages need Rust 1.78 or newer.
+#[proc_macro]
+pub fn assert_not_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(traits::expand_assert_not_impl(input.into()))
+}
@@ -0,0 +1,277 @@
+use quote::ToTokens;
+
+use crate::Generic;
+
+/// Maximum amount of bytes a single interpolated value can take up once formatted (`i128::MIN` being the longest).
+pub const MAX_VALUE_LEN: usize = 40;
+
+pub enum Segment {
+    Text(String),
+    Value(proc_macro2::TokenStream),
+}
+
+/// The failure message of an assertion.
+pub enum Message {
+    /// Passed to `panic!` as is.
+    Verbatim(Option<proc_macro2::TokenStream>),
+    /// A string literal, possibly containing `{N}` placeholders, formatted at compile time.
+    /// Followed by the `help:` and `note:` lines of the message.
+    Formatted(Vec<Segment>, Vec<Segment>),
+}
+
+/// Parses a string literal followed by any amount of `, help: "..."` and `, note: "..."` lines.
+/// Returns every literal along with the text preceding it in the message.
+fn parse_annotated(input: syn::parse::ParseStream) -> syn::Result<Vec<(String, syn::LitStr)>> {
+    let mut literals = vec![(String::new(), input.parse()?)];
+    while !input.is_empty() {
+        input.parse::<syn::Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+        let kind: syn::Ident = input.parse()?;
+        if kind != "help" && kind != "note" {
+            return Err(syn::Error::new(kind.span(), "Expected `help` or `note`."));
+        }
+        input.parse::<syn::Token![:]>()?;
+        literals.push((format!("\n{kind}: "), input.parse()?));
+    }
+    Ok(literals)
+}
+
+/// The message of an assertion without one, naming the failed `expression` and where the assertion is,
+/// such as `static assertion failed: N != 0 (src/buf.rs:42)`.
+pub fn failed(expression: &syn::Expr) -> proc_macro2::TokenStream {
+    quote::quote! { concat!("static assertion failed: ", stringify!(#expression), " (", file!(), ":", line!(), ")") }
+}
+
+/// `tokens` as text, spaced the way they'd usually be written (`N != 0`, `1..=64`, `align_of::<T>()`),
+/// for messages and docs that quote the input of a macro.
+pub fn source(tokens: impl ToTokens) -> String {
+    spaced(tokens, false)
+}
+
+/// [`source`] for types and trait bounds, whose angle brackets always enclose generic arguments (`Vec<T>`).
+pub fn type_source(tokens: impl ToTokens) -> String {
+    spaced(tokens, true)
+}
+
+fn spaced(tokens: impl ToTokens, is_type: bool) -> String {
+    let mut source = String::new();
+    // Whether the next token is written without a space before it, and whether the previous one ends an operand.
+    let (mut tight, mut operand) = (true, false);
+    // The depth of the generic arguments opened by a `::<` (or any `<` in a type), whose angle brackets aren't spaced.
+    let mut generics = 0;
+    let mut tokens = tokens.into_token_stream().into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            proc_macro2::TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    proc_macro2::Delimiter::Parenthesis => ("(", ")"),
+                    proc_macro2::Delimiter::Brace => ("{ ", " }"),
+                    proc_macro2::Delimiter::Bracket => ("[", "]"),
+                    proc_macro2::Delimiter::None => ("", ""),
+                };
+                let call = operand && group.delimiter() != proc_macro2::Delimiter::Brace;
+                if !tight && !call {
+                    source.push(' ');
+                }
+                source.push_str(open);
+                source.push_str(&spaced(group.stream(), is_type));
+                source.push_str(close);
+                (tight, operand) = (false, true);
+            }
+            proc_macro2::TokenTree::Punct(punct) => {
+                // Puncts joined to the following ones make up a single operator, such as `!=` or `..=`.
+                let mut op = punct.as_char().to_string();
+                let mut spacing = punct.spacing();
+                while spacing == proc_macro2::Spacing::Joint {
+                    let Some(proc_macro2::TokenTree::Punct(next)) = tokens.peek() else { break };
+                    op.push(next.as_char());
+                    spacing = next.spacing();
+                    tokens.next();
+                }
+
+                let (spaced_before, spaced_after, ends_operand) = match op.as_str() {
+                    "::" | "." | ".." | "..=" => (false, false, false),
+                    "," | ";" | ":" => (false, true, false),
+                    "?" => (false, false, true),
+                    "'" => (true, false, false),
+                    "!" if operand => (false, false, true),
+                    "<" if is_type || generics > 0 || source.ends_with("::") => {
+                        generics += 1;
+                        (false, false, false)
+                    }
+                    ">" | ">>" if generics > 0 => {
+                        generics -= op.len().min(generics);
+                        (false, false, true)
+                    }
+                    _ if !operand => (true, false, false),
+                    _ => (true, true, false),
+                };
+                if spaced_before && !tight {
+                    source.push(' ');
+                }
+                source.push_str(&op);
+                (tight, operand) = (!spaced_after, ends_operand);
+            }
+            token => {
+                if !tight {
+                    source.push(' ');
+                }
+                source.push_str(&token.to_string());
+                (tight, operand) = (false, true);
+            }
+        }
+    }
+    source
+}
+
+/// Whether `input` starts with a `, help: "..."` or `, note: "..."` line.
+pub fn peek_annotation(input: syn::parse::ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<syn::Token![,]>().is_ok()
+        && fork.parse::<syn::Ident>().is_ok_and(|kind| kind == "help" || kind == "note")
+        && fork.parse::<syn::Token![:]>().is_ok()
+        && fork.peek(syn::LitStr)
+}
+
+/// Splits a string literal into text and the values of its `{N}` placeholders, appending them to `segments` and `text`.
+fn interpolate(literal: &syn::LitStr, generics: &[Generic], inline_const: bool, segments: &mut Vec<Segment>, text: &mut String) -> syn::Result<()> {
+    let value = literal.value();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => { chars.next(); text.push('{'); }
+            '}' if chars.peek() == Some(&'}') => { chars.next(); text.push('}'); }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(syn::Error::new(literal.span(), "Unterminated `{` in message, use `{{` for a literal brace.")),
+                    }
+                }
+
+                // Inline `const` blocks can use any const generic of the outer item, declared or not.
+                let ident = match generics.iter().find_map(|g| g.const_ident().filter(|i| *i == name.trim())) {
+                    Some(ident) => ident.clone(),
+                    None if inline_const && syn::parse_str::<syn::Ident>(name.trim()).is_ok() => syn::Ident::new(name.trim(), literal.span()),
+                    None => return Err(syn::Error::new(literal.span(), format!(
+                        "`{{{name}}}` does not name a declared const generic. Only `{{N}}` placeholders with declared const generics are supported."
+                    ))),
+                };
+
+                segments.push(Segment::Text(std::mem::take(text)));
+                segments.push(Segment::Value(quote::quote! { #ident }));
+            }
+            '}' => return Err(syn::Error::new(literal.span(), "Unmatched `}` in message, use `}}` for a literal brace.")),
+            c => text.push(c),
+        }
+    }
+    Ok(())
+}
+
+impl Message {
+    /// Interpolates `{N}` placeholders in the message if it's a string literal, optionally followed by `help:` and `note:` lines.
+    /// Every placeholder needs to name one of the declared const generics, unless the assertion expands to an inline `const` block.
+    pub fn new(message: Option<proc_macro2::TokenStream>, generics: &[Generic], inline_const: bool) -> syn::Result<Self> {
+        let Some(literals) = message.clone().and_then(|tokens| syn::parse::Parser::parse2(parse_annotated, tokens).ok()) else {
+            return Ok(Message::Verbatim(message));
+        };
+
+        let mut parts = literals.iter().map(|(prefix, literal)| {
+            let mut segments = Vec::new();
+            let mut text = prefix.clone();
+            interpolate(literal, generics, inline_const, &mut segments, &mut text)?;
+            segments.push(Segment::Text(text));
+            Ok(segments)
+        }).collect::<syn::Result<Vec<_>>>()?.into_iter();
+
+        let segments = parts.next().unwrap_or_default();
+        Ok(Message::Formatted(segments, parts.flatten().collect()))
+    }
+
+    /// Replaces a missing message with the one from [`failed`].
+    pub fn or_failed(self, expression: &syn::Expr) -> Self {
+        match self {
+            Message::Verbatim(None) => {
+                let message = failed(expression);
+                Message::Verbatim(Some(quote::quote! { "{}", #message }))
+            }
+            message => message,
+        }
+    }
+
+    /// Builds the message of a comparison assertion, reporting both operands the way `assert_eq!` does.
+    /// `left` and `right` are expressions evaluating to the operands.
+    pub fn comparison(op: &str, message: Message, left: proc_macro2::TokenStream, right: proc_macro2::TokenStream) -> syn::Result<Self> {
+        let mut segments = vec![Segment::Text(format!("assertion `left {op} right` failed"))];
+        let mut annotations = Vec::new();
+        match message {
+            Message::Verbatim(None) => {}
+            Message::Verbatim(Some(tokens)) => {
+                return Err(syn::Error::new_spanned(tokens, "The message of a comparison assertion needs to be a string literal."));
+            }
+            Message::Formatted(message, notes) => {
+                segments.push(Segment::Text(": ".to_string()));
+                segments.extend(message);
+                annotations = notes;
+            }
+        }
+        segments.extend([
+            Segment::Text("\n  left: ".to_string()),
+            Segment::Value(left),
+            Segment::Text("\n right: ".to_string()),
+            Segment::Value(right),
+        ]);
+        Ok(Message::Formatted(segments, annotations))
+    }
+
+    /// The message as plain text, for attributes that only take string literals, with `value` giving the text of every value in it.
+    /// `None` unless the message is a string literal.
+    pub fn text(&self, value: impl Fn(&proc_macro2::TokenStream) -> String) -> Option<String> {
+        match self {
+            Message::Verbatim(_) => None,
+            Message::Formatted(segments, annotations) => Some(segments.iter().chain(annotations).map(|s| match s {
+                Segment::Text(text) => text.clone(),
+                Segment::Value(tokens) => value(tokens),
+            }).collect()),
+        }
+    }
+
+    fn has_values(&self) -> bool {
+        match self {
+            Message::Verbatim(_) => false,
+            Message::Formatted(segments, annotations) => segments.iter().chain(annotations).any(|s| matches!(s, Segment::Value(_))),
+        }
+    }
+
+    /// The `panic!` expression that's evaluated when the assertion fails, reported at `span`.
+    pub fn panic(&self, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+        match self {
+            Message::Verbatim(message) => quote::quote_spanned! {span=> panic!(#message) },
+            Message::Formatted(segments, annotations) if !self.has_values() => {
+                let text: String = segments.iter().chain(annotations).filter_map(|s| match s {
+                    Segment::Text(text) => Some(text.as_str()),
+                    Segment::Value(_) => None,
+                }).collect();
+                quote::quote_spanned! {span=> panic!("{}", #text) }
+            }
+            Message::Formatted(segments, annotations) => {
+                let capacity: usize = segments.iter().chain(annotations).map(|s| match s {
+                    Segment::Text(text) => text.len(),
+                    Segment::Value(_) => MAX_VALUE_LEN,
+                }).sum();
+                let pushes = segments.iter().chain(annotations).map(|s| match s {
+                    Segment::Text(text) => quote::quote! { .push_str(#text) },
+                    Segment::Value(value) => quote::quote! { .push_value(#value) },
+                });
+                quote::quote_spanned! {span=> {
+                    let message = ::static_assert_generic::__private::Message::<#capacity>::new() #(#pushes)*;
+                    panic!("{}", message.as_str())
+                }}
+            }
+        }
+    }
+}
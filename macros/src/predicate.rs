@@ -0,0 +1,160 @@
+use quote::ToTokens;
+use syn::spanned::Spanned;
+
+use crate::message::{source, type_source, Message, Segment};
+use crate::Generic;
+
+/// What an assertion checks: either an expression, or one of the predicates on a const generic.
+pub enum Condition {
+    Expression(syn::Expr),
+    Predicate(Predicate),
+}
+
+/// A predicate on a declared const generic of integer type, such as `N in 1..=64`, with a tailored default message.
+pub struct Predicate {
+    generic: syn::Ident,
+    test: Test,
+}
+
+enum Test {
+    /// `N in 1..=64`, with any kind of range.
+    Range(syn::ExprRange),
+    /// `N in [1, 2, 4, 8]`
+    Set(syn::punctuated::Punctuated<syn::Expr, syn::Token![,]>),
+    /// `N is power_of_two`
+    PowerOfTwo,
+    /// `N is multiple_of(8)`
+    MultipleOf(syn::Expr),
+    /// `N fits u16`
+    Fits(syn::Type),
+}
+
+/// Whether `input` starts with a predicate, that is an identifier followed by `in`, `is` or `fits`.
+fn peek_predicate(input: syn::parse::ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<syn::Ident>().is_ok()
+        && (fork.peek(syn::Token![in]) || fork.parse::<syn::Ident>().is_ok_and(|keyword| keyword == "is" || keyword == "fits"))
+}
+
+impl syn::parse::Parse for Condition {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if !peek_predicate(input) {
+            return Ok(Condition::Expression(input.parse()?));
+        }
+
+        let generic = input.parse()?;
+        let test = if input.parse::<syn::Token![in]>().is_ok() {
+            if input.peek(syn::token::Bracket) {
+                let values_buf;
+                syn::bracketed!(values_buf in input);
+                Test::Set(values_buf.parse_terminated(syn::Expr::parse, syn::Token![,])?)
+            } else {
+                match input.parse()? {
+                    syn::Expr::Range(range) => Test::Range(range),
+                    expression => return Err(syn::Error::new_spanned(expression, "Expected a range (`1..=64`) or a list of values (`[1, 2, 4, 8]`).")),
+                }
+            }
+        } else if input.parse::<syn::Ident>()? == "is" {
+            let property: syn::Ident = input.parse()?;
+            if property == "power_of_two" {
+                Test::PowerOfTwo
+            } else if property == "multiple_of" {
+                let divisor_buf;
+                syn::parenthesized!(divisor_buf in input);
+                Test::MultipleOf(divisor_buf.parse()?)
+            } else {
+                return Err(syn::Error::new(property.span(), "Expected `power_of_two` or `multiple_of(..)`."));
+            }
+        } else {
+            let ty = input.parse()?;
+            if !is_integer_type(&ty) {
+                return Err(syn::Error::new_spanned(ty, "Expected an integer type."));
+            }
+            Test::Fits(ty)
+        };
+        Ok(Condition::Predicate(Predicate { generic, test }))
+    }
+}
+
+impl Condition {
+    pub fn span(&self) -> proc_macro2::Span {
+        match self {
+            Condition::Expression(expression) => expression.span(),
+            Condition::Predicate(predicate) => predicate.generic.span(),
+        }
+    }
+}
+
+impl ToTokens for Condition {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Condition::Expression(expression) => expression.to_tokens(tokens),
+            Condition::Predicate(Predicate { generic, test }) => tokens.extend(match test {
+                Test::Range(range) => quote::quote! { #generic in #range },
+                Test::Set(values) => quote::quote! { #generic in [#values] },
+                Test::PowerOfTwo => quote::quote! { #generic is power_of_two },
+                Test::MultipleOf(divisor) => quote::quote! { #generic is multiple_of(#divisor) },
+                Test::Fits(ty) => quote::quote! { #generic fits #ty },
+            }),
+        }
+    }
+}
+
+fn is_integer_type(ty: &syn::Type) -> bool {
+    crate::is_const_generic_type(ty) && !matches!(ty, syn::Type::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == "bool" || s.ident == "char"))
+}
+
+/// `expression` as an operand of a binary operator, parenthesized unless it's a single term.
+fn operand(expression: &syn::Expr) -> proc_macro2::TokenStream {
+    match expression {
+        syn::Expr::Lit(_) | syn::Expr::Path(_) | syn::Expr::Call(_) | syn::Expr::MethodCall(_) | syn::Expr::Paren(_) => expression.to_token_stream(),
+        expression => quote::quote! { (#expression) },
+    }
+}
+
+impl Predicate {
+    /// The expression checking the predicate, and its message, which defaults to a tailored one such as `N = 100 is not in 1..=64`.
+    /// The generic needs to be one of the declared const generics, of integer type.
+    pub fn check(&self, message: Message, generics: &[Generic]) -> syn::Result<(syn::Expr, Message)> {
+        let generic = &self.generic;
+        // Types that can't be told from their name, such as type aliases, are left for the compiler to check.
+        let declared = generics.iter().any(|g| matches!(g, Generic::Const(i, t) if i == generic && (is_integer_type(t) || !crate::is_const_generic_type(t))));
+        if !declared {
+            return Err(syn::Error::new(generic.span(), format!("`{generic}` needs to be a declared const generic of integer type, such as `({generic}: usize)`.")));
+        }
+
+        let (expression, failure) = match &self.test {
+            Test::Range(range) => {
+                let start = range.start.as_deref().map(|start| quote::quote! { #generic >= #start });
+                let end = range.end.as_deref().map(|end| match range.limits {
+                    syn::RangeLimits::HalfOpen(_) => quote::quote! { #generic < #end },
+                    syn::RangeLimits::Closed(_) => quote::quote! { #generic <= #end },
+                });
+                let bounds: Vec<_> = start.into_iter().chain(end).collect();
+                let expression = if bounds.is_empty() { quote::quote! { true } } else { quote::quote! { #(#bounds)&&* } };
+                (expression, format!(" is not in {}", source(range)))
+            }
+            Test::Set(values) => {
+                let operands = values.iter().map(operand);
+                let expression = if values.is_empty() { quote::quote! { false } } else { quote::quote! { #(#generic == #operands)||* } };
+                (expression, format!(" is not in [{}]", values.iter().map(source).collect::<Vec<_>>().join(", ")))
+            }
+            Test::PowerOfTwo => (quote::quote! { #generic > 0 && #generic.count_ones() == 1 }, " is not a power of two".to_string()),
+            Test::MultipleOf(divisor) => {
+                let divisor_operand = operand(divisor);
+                (quote::quote! { #generic % #divisor_operand == 0 }, format!(" is not a multiple of {}", source(divisor)))
+            }
+            Test::Fits(ty) => (quote::quote! { ::static_assert_generic::__private::fits::<#ty, _>(#generic) }, format!(" does not fit in {}", type_source(ty))),
+        };
+
+        let message = match message {
+            Message::Verbatim(None) => Message::Formatted(vec![
+                Segment::Text(format!("{generic} = ")),
+                Segment::Value(generic.to_token_stream()),
+                Segment::Text(failure),
+            ], Vec::new()),
+            message => message,
+        };
+        Ok((syn::parse2(expression)?, message))
+    }
+}
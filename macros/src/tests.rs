@@ -0,0 +1,95 @@
+//! Snapshot tests of the code the macros expand to.
+//!
+//! Every snapshot in `snapshots` holds the call to a macro, followed by its pretty-printed expansion with inline `const` blocks and without them.
+//! After reviewing a change to the expansions, run the tests with `UPDATE_SNAPSHOTS=1` to write them to the snapshots.
+
+use std::path::Path;
+
+type Expand = fn(proc_macro2::TokenStream, bool) -> syn::Result<proc_macro2::TokenStream>;
+
+/// Places an expansion in an item named after whether it uses inline `const` blocks, so that the snapshot is a valid file.
+type Wrap = fn(bool, proc_macro2::TokenStream) -> proc_macro2::TokenStream;
+
+fn wrap_in_fn(inline_const: bool, expansion: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let name = quote::format_ident!("{}", if inline_const { "inline_const" } else { "without_inline_const" });
+    quote::quote! { fn #name() { #expansion; } }
+}
+
+fn wrap_in_impl(inline_const: bool, expansion: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let name = quote::format_ident!("{}", if inline_const { "InlineConst" } else { "WithoutInlineConst" });
+    quote::quote! { impl Drop for #name { #expansion } }
+}
+
+/// Expands `macro_name!(input)`, and compares it with the snapshot `name`, returning the differences if there are any.
+fn snapshot(name: &str, macro_name: &str, input: &str, expand: Expand, wrap: Wrap) -> Option<String> {
+    let tokens: proc_macro2::TokenStream = input.parse().unwrap();
+    let expansions = [true, false].map(|inline_const| {
+        let expansion = expand(tokens.clone(), inline_const).unwrap_or_else(|err| panic!("{name}: {err}"));
+        wrap(inline_const, expansion)
+    });
+    let file = syn::parse2(quote::quote! { #(#expansions)* }).unwrap_or_else(|err| panic!("{name}: {err}"));
+    let actual = format!("// {macro_name}!({input})\n\n{}", prettyplease::unparse(&file));
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots").join(format!("{name}.rs"));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+        return None;
+    }
+
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if expected == actual => None,
+        Ok(expected) => Some(format!("{name}: expected\n{expected}\ngot\n{actual}")),
+        Err(_) => Some(format!("{name}: no snapshot, got\n{actual}")),
+    }
+}
+
+fn check(macro_name: &str, expand: Expand, wrap: Wrap, cases: &[(&str, &str)]) {
+    let failures: Vec<String> = cases.iter()
+        .filter_map(|(name, input)| snapshot(&format!("{macro_name}_{name}"), macro_name, input, expand, wrap))
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+#[test]
+fn static_assert() {
+    check("static_assert", crate::expand_static_assert, wrap_in_fn, &[
+        ("empty", "() 1 + 2 < 17"),
+        ("empty_message", r#"() 1 + 2 < 17 => "Math is broken!""#),
+        ("const", "(N: usize) N != 0"),
+        ("const_message", r#"(N: usize) N != 0 => "N must be non-zero!""#),
+        ("const_interpolated", r#"(N: usize, C: char) N <= 64 => "N must be <= 64, got {N} (for {C})""#),
+        ("type", "(T) std::mem::size_of::<T>() == 4"),
+        ("type_message", r#"(T) std::mem::size_of::<T>() == 4 => "T must be 4 bytes long!""#),
+        ("type_bounds", "(T: HasCapacity + Copy) T::CAPACITY > 0"),
+        ("unsized", "(U?) std::mem::size_of::<&U>() == 8"),
+        ("unsized_message", r#"(U?) std::mem::size_of::<&U>() == 8 => "References to U must be thin!""#),
+        ("unsized_bounds", "(U?: std::fmt::Debug) true"),
+        ("lifetime", "(T, 'a) std::mem::size_of::<&'a T>() == 8"),
+        ("mixed", "(N: usize, T, U?, 'a) N == std::mem::size_of::<&'a U>() / std::mem::size_of::<T>()"),
+        ("mixed_message", r#"(N: usize, T, U?, 'a) N == std::mem::size_of::<&'a U>() / std::mem::size_of::<T>() => "N = {N} is wrong!""#),
+        ("where_clause", "(T: IntoIterator) where T::Item: HasCapacity; <T::Item as HasCapacity>::CAPACITY > 0"),
+        ("angle_brackets", "<'a, const N: usize, T: ?Sized + 'a> N <= std::mem::size_of::<&'a T>()"),
+        ("angle_brackets_outlives", "<'a, 'b, T, U> std::mem::size_of::<&'a T>() == std::mem::size_of::<&'b [U]>()"),
+        ("block", r#"(N: usize, T) { N > 0 => "N must be non-zero!", std::mem::size_of::<T>() <= N }"#),
+        ("compound", "(N: usize, M: usize) N > 0 && (M < N || !(M == 0)) && N % M == 0"),
+        ("annotated", r#"(N: usize) N <= 64 => "N = {N} is too large!", help: "use a Vec instead", note: "N is the length of an array""#),
+        ("block_annotated", r#"(N: usize, T) { N > 0 => "N must be non-zero!", note: "N is a divisor", std::mem::size_of::<T>() <= N }"#),
+        ("cfg", r#"#[cfg(debug_assertions)] #[cfg(feature = "strict")] (N: usize) N != 0 => "N must be non-zero!""#),
+        ("range", "(N: usize) N in 1..=64"),
+        ("set", "(N: u32) N in [1, 2, 4, 8]"),
+        ("predicates", r#"(N: usize, T) { N is power_of_two, N is multiple_of(std::mem::align_of::<T>()), N fits u16 => "N = {N} is too large!" }"#),
+        ("evidence", r#"(N: usize) -> NonZero<N> N != 0 => "N must be non-zero!""#),
+    ]);
+}
+
+#[test]
+fn explicitly_drop() {
+    check("explicitly_drop", crate::expand_explicitly_drop, wrap_in_impl, &[
+        ("const", "C: u8"),
+        ("const_message", r#"C: u8 => "C = {C} must be dropped explicitly!""#),
+        ("type", "T"),
+        ("type_message", r#"T => "Allocation must be freed explicitly!""#),
+        ("unsized", "T?"),
+        ("unsized_message", r#"T? => "Allocation must be freed explicitly!""#),
+    ]);
+}
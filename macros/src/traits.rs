@@ -0,0 +1,293 @@
+//! Assertions on the traits implemented by types. They're checked while type-checking rather than by evaluating constants,
+//! so they fail `cargo check` as well, and generic types are checked for every instantiation of the declared generics at once.
+
+use syn::spanned::Spanned;
+
+use crate::message::type_source;
+use crate::{parse_generics_then, phantom_types, Bounds, Generic, Generics};
+
+/// `type_source(tokens)`, escaped for the format strings of `#[diagnostic::on_unimplemented]`.
+fn diagnostic_text(tokens: impl quote::ToTokens) -> String {
+    type_source(tokens).replace('{', "{{").replace('}', "}}")
+}
+
+/// Parses a trait asserted on a type, rejecting `?Sized` bounds, which don't assert anything.
+fn parse_bound(input: syn::parse::ParseStream) -> syn::Result<syn::TypeParamBound> {
+    let bound = input.parse()?;
+    if crate::is_maybe_sized(&bound) {
+        return Err(syn::Error::new_spanned(bound, "`?Sized` bounds can't be asserted."));
+    }
+    Ok(bound)
+}
+
+/// Parses an optional `=> "message"` replacing the default message of a failed assertion.
+fn parse_message(input: syn::parse::ParseStream) -> syn::Result<Option<syn::LitStr>> {
+    Ok(if input.parse::<syn::Token![=>]>().is_ok() { Some(input.parse()?) } else { None })
+}
+
+/// Tells whether a type implements traits while type-checking, through the `Probe` struct.\
+/// `Probe` holds the probed type after the lifetimes of the declared generics, followed by the rest of them.
+/// For every probed trait, an inherent method whose impl requires the trait is picked by method resolution over the method
+/// of the same name of a trait implemented for every `Probe` if (and only if) the trait is implemented.
+/// Unlike a failed trait bound, whose error is reported at whatever nested type doesn't implement the trait,
+/// and an ambiguous impl, whose error is a `type annotations needed`, the type returned by the picked method
+/// can then be required to implement a trait with a custom diagnostic.
+struct Probe {
+    /// The declared generics, with lifetimes first.
+    generics: Vec<Generic>,
+    implied_outlives: bool,
+    where_clause: Option<syn::WhereClause>,
+    /// The probed traits, in the order of their `implements_{i}` methods.
+    bounds: Vec<syn::TypeParamBound>,
+    /// The diagnostic traits of the requirements, along with the `require_{i}` functions requiring them.
+    requirements: Vec<proc_macro2::TokenStream>,
+    /// The statements of the `check` function, requiring every requirement.
+    checks: Vec<proc_macro2::TokenStream>,
+    /// Whether the results of probing are combined with `or` and `and`.
+    combined: bool,
+}
+
+impl Probe {
+    fn new(generics: &Generics) -> Self {
+        let mut params = generics.params.clone();
+        params.sort_by_key(|generic| !matches!(generic, Generic::Lifetime(_)));
+        Probe {
+            generics: params,
+            implied_outlives: generics.implied_outlives,
+            where_clause: generics.where_clause.clone(),
+            bounds: Vec::new(),
+            requirements: Vec::new(),
+            checks: Vec::new(),
+            combined: false,
+        }
+    }
+
+    /// The definitions of the generics of `Probe`, with the probed type given `bounds`.
+    fn definitions(&self, bounds: Bounds) -> proc_macro2::TokenStream {
+        let probed = Generic::UnsizedType(syn::Ident::new("__Probed", proc_macro2::Span::call_site()), bounds);
+        self.generics.iter().filter(|g| matches!(g, Generic::Lifetime(_)))
+            .chain(Some(&probed))
+            .chain(self.generics.iter().filter(|g| !matches!(g, Generic::Lifetime(_))))
+            .map(Generic::definition)
+            .collect()
+    }
+
+    /// The path to `Probe`, with `probed` as the probed type.
+    fn path(&self, probed: impl quote::ToTokens) -> proc_macro2::TokenStream {
+        let lifetimes = self.generics.iter().filter(|g| matches!(g, Generic::Lifetime(_))).map(Generic::placement);
+        let rest = self.generics.iter().filter(|g| !matches!(g, Generic::Lifetime(_))).map(Generic::placement);
+        quote::quote! { Probe::<#(#lifetimes)* #probed, #(#rest)*> }
+    }
+
+    /// An expression evaluating to `Implemented` if `ty` implements `bound`, and to `NotImplemented` otherwise.
+    fn implements(&mut self, ty: &syn::Type, bound: &syn::TypeParamBound) -> proc_macro2::TokenStream {
+        let method = quote::format_ident!("implements_{}", self.bounds.len());
+        self.bounds.push(bound.clone());
+        let probed = self.path(ty);
+        quote::quote! { #probed(core::marker::PhantomData).#method() }
+    }
+
+    /// An expression evaluating to `Implemented` if any of (or, unless `any`, all of) the `values` evaluate to it.
+    fn combine(&mut self, values: Vec<proc_macro2::TokenStream>, any: bool) -> proc_macro2::TokenStream {
+        self.combined = true;
+        let (initial, method) = if any { ("NotImplemented", "or") } else { ("Implemented", "and") };
+        let (initial, method) = (syn::Ident::new(initial, proc_macro2::Span::call_site()), syn::Ident::new(method, proc_macro2::Span::call_site()));
+        quote::quote! { #initial #(.#method(#values))* }
+    }
+
+    /// Requires `value` to be of type `expected` (`Implemented` or `NotImplemented`), failing with `message` and `label` at `span` otherwise.
+    fn require(&mut self, value: proc_macro2::TokenStream, expected: &str, message: String, label: String, span: proc_macro2::Span) {
+        let i = self.requirements.len();
+        let (requirement, require) = (quote::format_ident!("Requirement{i}"), quote::format_ident!("require_{i}"));
+        let expected = syn::Ident::new(expected, proc_macro2::Span::call_site());
+        // Older compilers only report that `Requirement{i}` isn't implemented for the type the probe evaluated to.
+        let diagnostic = crate::DIAGNOSTIC_NAMESPACE.then(|| quote::quote! {
+            #[diagnostic::on_unimplemented(message = #message, label = #label)]
+        });
+        self.requirements.push(quote::quote! {
+            #diagnostic
+            trait #requirement {}
+            impl #requirement for #expected {}
+            fn #require<R: #requirement>(_: R) {}
+        });
+        self.checks.push(quote::quote_spanned! {span=> #require(#value); });
+    }
+
+    /// The items checking the requirements, in a `const _` item.
+    fn expand(self) -> proc_macro2::TokenStream {
+        let Probe { generics, implied_outlives, where_clause, bounds, requirements, checks, combined } = &self;
+        let definitions = self.definitions(Bounds::new());
+        let phantom_types = phantom_types(generics, *implied_outlives);
+        let generic_definitions: proc_macro2::TokenStream = generics.iter().map(Generic::definition).collect();
+        let (probe_of_any, unit_probe) = (self.path(quote::quote! { __Probed }), self.path(quote::quote! { () }));
+
+        let methods: Vec<syn::Ident> = (0..bounds.len()).map(|i| quote::format_ident!("implements_{i}")).collect();
+        let bound_definitions = bounds.iter().map(|bound| self.definitions(std::iter::once(bound.clone()).collect()));
+        let combinators = combined.then(|| quote::quote! {
+            impl Implemented {
+                fn or<R>(self, _: R) -> Implemented { self }
+                fn and<R>(self, other: R) -> R { other }
+            }
+            impl NotImplemented {
+                fn or<R>(self, other: R) -> R { other }
+                fn and<R>(self, _: R) -> NotImplemented { self }
+            }
+        });
+
+        // The parameter of `check` implies the bounds needed by `Probe`, such as the declared types outliving the declared lifetimes.
+        quote::quote! {
+            const _: () = {
+                struct Implemented;
+                struct NotImplemented;
+                #combinators
+                struct Probe<#definitions>(core::marker::PhantomData<(*const __Probed, #(*const #phantom_types,)*)>);
+
+                #(
+                    impl<#bound_definitions> #probe_of_any #where_clause {
+                        fn #methods(&self) -> Implemented { Implemented }
+                    }
+                )*
+                trait Fallback {
+                    #(fn #methods(&self) -> NotImplemented { NotImplemented })*
+                }
+                impl<#definitions> Fallback for #probe_of_any {}
+
+                #(#requirements)*
+
+                #[allow(unused)]
+                fn check<#generic_definitions>(_: #unit_probe) #where_clause {
+                    #(#checks)*
+                }
+            };
+        }
+    }
+}
+
+/// The input of `assert_impl!`: a type, and the traits it's asserted to implement.
+struct AssertImplInput {
+    generics: Generics,
+    ty: syn::Type,
+    bounds: Vec<syn::TypeParamBound>,
+    message: Option<syn::LitStr>,
+}
+
+impl syn::parse::Parse for AssertImplInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let (generics, (ty, bounds, message)) = parse_generics_then(input, |input| {
+            let ty = input.parse()?;
+            input.parse::<syn::Token![:]>()?;
+            let mut bounds = vec![parse_bound(input)?];
+            while input.parse::<Option<syn::Token![+]>>()?.is_some() {
+                bounds.push(parse_bound(input)?);
+            }
+            Ok((ty, bounds, parse_message(input)?))
+        })?;
+        Ok(AssertImplInput { generics, ty, bounds, message })
+    }
+}
+
+pub fn expand_assert_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let AssertImplInput { generics, ty, bounds, message } = syn::parse2(input)?;
+    let mut probe = Probe::new(&generics);
+
+    // Every trait gets its own diagnostic, so that the error names the one that isn't implemented.
+    for bound in &bounds {
+        let implements = probe.implements(&ty, bound);
+        let message = message.as_ref().map(syn::LitStr::value)
+            .unwrap_or_else(|| format!("`{}` doesn't implement `{}`", diagnostic_text(&ty), diagnostic_text(bound)));
+        let label = format!("asserted to implement `{}`", diagnostic_text(bound));
+        probe.require(implements, "Implemented", message, label, ty.span());
+    }
+    Ok(probe.expand())
+}
+
+/// The traits asserted not to be implemented: a single one, or `any(...)` or `all(...)` of a list of them, which can be nested.
+enum Traits {
+    Trait(syn::TypeParamBound),
+    Any(Vec<Traits>),
+    All(Vec<Traits>),
+}
+
+impl syn::parse::Parse for Traits {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        let combinator = fork.parse::<syn::Ident>().ok().filter(|ident| (ident == "any" || ident == "all") && fork.peek(syn::token::Paren));
+        let Some(combinator) = combinator else {
+            return Ok(Traits::Trait(parse_bound(input)?));
+        };
+
+        input.parse::<syn::Ident>()?;
+        let traits_buf;
+        syn::parenthesized!(traits_buf in input);
+        let traits: Vec<Traits> = traits_buf.parse_terminated(Traits::parse, syn::Token![,])?.into_iter().collect();
+        if traits.is_empty() {
+            return Err(syn::Error::new(combinator.span(), format!("Expected at least one trait in `{combinator}(...)`.")));
+        }
+        Ok(if combinator == "any" { Traits::Any(traits) } else { Traits::All(traits) })
+    }
+}
+
+impl Traits {
+    /// The traits as written, such as `Send` or `all(Send, Sync)`.
+    fn text(&self) -> String {
+        let list = |traits: &[Traits]| traits.iter().map(Traits::text).collect::<Vec<_>>().join(", ");
+        match self {
+            Traits::Trait(bound) => diagnostic_text(bound),
+            Traits::Any(traits) => format!("any({})", list(traits)),
+            Traits::All(traits) => format!("all({})", list(traits)),
+        }
+    }
+
+    /// An expression evaluating to `Implemented` if `ty` implements the traits, and to `NotImplemented` otherwise.
+    fn implemented(&self, ty: &syn::Type, probe: &mut Probe) -> proc_macro2::TokenStream {
+        match self {
+            Traits::Trait(bound) => probe.implements(ty, bound),
+            Traits::Any(traits) | Traits::All(traits) => {
+                let values = traits.iter().map(|traits| traits.implemented(ty, probe)).collect();
+                probe.combine(values, matches!(self, Traits::Any(_)))
+            }
+        }
+    }
+}
+
+/// The input of `assert_not_impl!`: a type, and the traits it's asserted not to implement.
+struct AssertNotImplInput {
+    generics: Generics,
+    ty: syn::Type,
+    traits: Traits,
+    message: Option<syn::LitStr>,
+}
+
+impl syn::parse::Parse for AssertNotImplInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let (generics, (ty, traits, message)) = parse_generics_then(input, |input| {
+            let ty = input.parse()?;
+            input.parse::<syn::Token![:]>()?;
+            let traits = input.parse()?;
+            if input.peek(syn::Token![+]) {
+                return Err(input.error("Use `any(A, B)` or `all(A, B)` to assert on more than one trait."));
+            }
+            Ok((ty, traits, parse_message(input)?))
+        })?;
+        Ok(AssertNotImplInput { generics, ty, traits, message })
+    }
+}
+
+pub fn expand_assert_not_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let AssertNotImplInput { generics, ty, traits, message } = syn::parse2(input)?;
+    let mut probe = Probe::new(&generics);
+
+    // None of the traits of `any(...)` may be implemented, so each of them gets its own diagnostic, naming the one that's implemented.
+    let asserted = match traits {
+        Traits::Any(traits) => traits,
+        traits => vec![traits],
+    };
+    for traits in &asserted {
+        let implemented = traits.implemented(&ty, &mut probe);
+        let message = message.as_ref().map(syn::LitStr::value)
+            .unwrap_or_else(|| format!("`{}` implements `{}`", diagnostic_text(&ty), traits.text()));
+        let label = format!("asserted not to implement `{}`", traits.text());
+        probe.require(implemented, "NotImplemented", message, label, ty.span());
+    }
+    Ok(probe.expand())
+}
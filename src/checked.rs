@@ -0,0 +1,55 @@
+//! Evidence that an assertion passed, which functions relying on it can take as a parameter.
+
+use core::marker::PhantomData;
+
+/// Zero-sized evidence that an assertion on the proposition `P` passed, produced by `static_assert!((..) -> P ...)`.
+///
+/// `P` is any type naming what was asserted, usually an uninhabited one with the generics it's about:
+///
+/// ```
+/// # use static_assert_generic::*;
+/// enum NonZero<const N: usize> {}
+///
+/// fn chunks<const N: usize>(data: &[u8]) -> usize {
+///     let proof: Checked<NonZero<N>> = static_assert!((N: usize) -> NonZero<N> N != 0 => "N must be non-zero");
+///     count::<N>(data, proof)
+/// }
+///
+/// fn count<const N: usize>(data: &[u8], _: Checked<NonZero<N>>) -> usize {
+///     data.len() / N
+/// }
+/// ```
+///
+/// Evidence can only be created by an assertion, but any assertion can name any proposition,
+/// so the assertions naming a proposition are best kept next to its declaration.
+pub struct Checked<P: ?Sized>(PhantomData<fn() -> P>);
+
+impl<P: ?Sized> Clone for Checked<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: ?Sized> Copy for Checked<P> {}
+
+impl<P: ?Sized> core::fmt::Debug for Checked<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Checked<{}>", core::any::type_name::<P>())
+    }
+}
+
+/// Fails to compile once evidence of `P` is produced while assertions are turned off, since nothing checked it.
+struct Enabled<P: ?Sized>(PhantomData<fn() -> P>);
+
+impl<P: ?Sized> Enabled<P> {
+    const CHECK: () = assert!(
+        crate::__private::ENABLED,
+        "`->` evidence can't be produced while assertions are turned off by the `off` feature or `--cfg static_assert_generic_off`"
+    );
+}
+
+/// The evidence produced by an assertion naming `P`, once its checks are in place.
+pub const fn checked<P: ?Sized>() -> Checked<P> {
+    let () = Enabled::<P>::CHECK;
+    Checked(PhantomData)
+}
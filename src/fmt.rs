@@ -0,0 +1,121 @@
+//! A const-evaluable formatter for integer, `bool` and `char` values, used to interpolate values into failure messages.
+
+pub enum Kind { Unsigned, Signed, Bool, Char }
+
+pub trait Value: Copy { const KIND: Kind; }
+
+macro_rules! impl_value {
+    ($kind:ident: $($ty:ty),*) => { $(impl Value for $ty { const KIND: Kind = Kind::$kind; })* };
+}
+
+impl_value!(Unsigned: u8, u16, u32, u64, u128, usize);
+impl_value!(Signed: i8, i16, i32, i64, i128, isize);
+impl_value!(Bool: bool);
+impl_value!(Char: char);
+
+union Bits<T: Copy> { value: T, bytes: [u8; 16] }
+
+/// Whether an integer `value` is negative, along with its magnitude. `bool` and `char` values are never negative.
+pub const fn magnitude<T: Value>(value: T) -> (bool, u128) {
+    let size = core::mem::size_of::<T>();
+    let mut bits = Bits::<T> { bytes: [0; 16] };
+    bits.value = value;
+    // SAFETY: every byte of `bits` was initialized when it was created, and `value` is an integer, `bool` or `char` without padding.
+    let mut raw = u128::from_ne_bytes(unsafe { bits.bytes });
+    if cfg!(target_endian = "big") && size < 16 {
+        raw >>= (16 - size) * 8;
+    }
+
+    match T::KIND {
+        Kind::Signed => {
+            let shift = 128 - size as u32 * 8;
+            let value = ((raw << shift) as i128) >> shift;
+            (value < 0, value.unsigned_abs())
+        }
+        Kind::Unsigned | Kind::Bool | Kind::Char => (false, raw),
+    }
+}
+
+/// A message of at most `CAP` bytes, built up by chaining pushes.
+#[derive(Clone, Copy)]
+pub struct Message<const CAP: usize> { buf: [u8; CAP], len: usize }
+
+impl<const CAP: usize> Message<CAP> {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Message { buf: [0; CAP], len: 0 }
+    }
+
+    const fn push_byte(mut self, byte: u8) -> Self {
+        self.buf[self.len] = byte;
+        self.len += 1;
+        self
+    }
+
+    pub const fn push_str(mut self, s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self = self.push_byte(bytes[i]);
+            i += 1;
+        }
+        self
+    }
+
+    const fn push_u128(mut self, mut value: u128) -> Self {
+        let mut digits = [0u8; 39];
+        let mut len = 0;
+        loop {
+            digits[len] = b'0' + (value % 10) as u8;
+            len += 1;
+            value /= 10;
+            if value == 0 { break; }
+        }
+        while len > 0 {
+            len -= 1;
+            self = self.push_byte(digits[len]);
+        }
+        self
+    }
+
+    const fn push_char(self, c: char) -> Self {
+        let c = c as u32;
+        if c < 0x80 {
+            self.push_byte(c as u8)
+        } else if c < 0x800 {
+            self.push_byte(0xC0 | (c >> 6) as u8)
+                .push_byte(0x80 | (c & 0x3F) as u8)
+        } else if c < 0x10000 {
+            self.push_byte(0xE0 | (c >> 12) as u8)
+                .push_byte(0x80 | ((c >> 6) & 0x3F) as u8)
+                .push_byte(0x80 | (c & 0x3F) as u8)
+        } else {
+            self.push_byte(0xF0 | (c >> 18) as u8)
+                .push_byte(0x80 | ((c >> 12) & 0x3F) as u8)
+                .push_byte(0x80 | ((c >> 6) & 0x3F) as u8)
+                .push_byte(0x80 | (c & 0x3F) as u8)
+        }
+    }
+
+    pub const fn push_value<T: Value>(self, value: T) -> Self {
+        let (negative, raw) = magnitude(value);
+        match T::KIND {
+            Kind::Unsigned | Kind::Signed => {
+                let this = if negative { self.push_byte(b'-') } else { self };
+                this.push_u128(raw)
+            }
+            Kind::Bool => self.push_str(if raw != 0 { "true" } else { "false" }),
+            Kind::Char => match char::from_u32(raw as u32) {
+                Some(c) => self.push_char(c),
+                None => self.push_str("\u{FFFD}"),
+            },
+        }
+    }
+
+    pub const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.buf.split_at(self.len).0) {
+            Ok(s) => s,
+            Err(_) => "<invalid message>",
+        }
+    }
+}
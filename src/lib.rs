@@ -7,70 +7,99 @@ and full `cargo build` compilations are needed instead.
 This is a rather 'hack'y method of doing asserts, so I wouldn't be that surprised if future versions of rust break it.
 For now, it still works as of 1.77.2.
 
+On Rust 1.79 and newer (detected by the build script), assertions expand to inline `const { }` blocks instead,
+which can use the generics of the surrounding item directly.
+The generics list is then only needed for compatibility with older compilers, and can be omitted:
+```
+# use static_assert_generic::*;
+fn foo<const N: usize>() {
+    static_assert!(N != 0 => "N must be a non-zero value, got {N}!");
+}
+```
+`static_assert_instantiations!` is the exception, since its instantiations are checked outside of any generic item.
+
+Without a generics list, a condition starting with parentheses could be mistaken for one.
+Parentheses that form a valid generics list are taken as one, unless they're followed by `-`, `*`, `!` or `&`,
+which could just as well continue the condition (`(N) - 1 < M`). That's an error, to be resolved by wrapping the condition
+in parentheses (`((N) - 1 < M)`), or by writing the generics list in angle brackets.
+
 Attempts to add const generic functionality in the `static_assert` crate [have been made](https://github.com/nvzqz/static-assertions/issues/40),
 but it doesn't seem like it'll be added anytime soon.
 
-These asserts are not present in function signatures or the type system in any way, possibly making it hard to reason about when creating any kind of abstraction.
-You should probably use them sparingly and explicitly document them whenever they are used.
+These asserts are not present in function signatures or the type system by default, possibly making it hard to reason about when creating any kind of abstraction.
+You should probably use them sparingly and explicitly document them whenever they are used,
+or have them produce [`Checked`] evidence that functions relying on them can take as a parameter.
 
 # Overview
 
 Static asserts error conditionally, depending on the value of the generic:
-```
+```compile_fail,E0080
+# use static_assert_generic::*;
 fn foo<const N: usize>() {
-    static_assert!((N: usize) N != 0 => "N must be a non-zero value!");
-    // Some other functionality.
+    static_assert!((N: usize) N != 0 => "N must be a non-zero value!");
+    // Some other functionality.
 }
 
 fn main() {
-    foo::<12>(); // compiles
-    foo::<0>(); // doesn't compile
+    foo::<12>(); // compiles
+    foo::<0>(); // doesn't compile
 }
 ```
 
 ```
 // error[E0080]: evaluation of `foo::Assert::<0>::CHECK` failed
-//  |         static_assert!((N: usize) N != 0 => "N must be a non-zero value!");
-//  |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'N must be a non-zero value!'
+//  |         static_assert!((N: usize) N != 0 => "N must be a non-zero value!");
+//  |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'N must be a non-zero value!'
 //
 // note: the above error was encountered while instantiating `fn main::foo::<0>`
-//  |     foo::<0>();
+//  |     foo::<0>();
 ```
 
 # Important #1
 Static asserts that fail (such as `foo::<0>()` in this case) will not show an error when using `cargo check`.
 However, attempting to compile (using `cargo build`) still results in an error, as expected.
 
+Known instantiations can be checked by `cargo check` too, by listing them with `static_assert_instantiations!`:
+```
+# use static_assert_generic::*;
+static_assert_instantiations!(for (N: usize) in [1, 12, 64] N != 0 => "N must be a non-zero value!");
+```
+
 # Important #2
-Not specifying the type of the const generic will result in a `can't use generic parameters from outer item` error:
+Not specifying the type of a const generic declares a type generic instead, which results in a `can't use generic parameters from outer item` error
+on compilers older than 1.79, and an `expected type, found const parameter` error on newer ones:
 
-```
+```compile_fail
+# use static_assert_generic::*;
 fn foo<const N: u32>() {
-    static_assert!((N) N != 0 => "N must be a non-zero value!");
-    // can't use generic parameters from outer item
+    static_assert!((N) N != 0 => "N must be a non-zero value!");
+    // can't use generic parameters from outer item
 }
 ```
 
 This is not the macro being broken, this is just a misleading error message.
 It can be fixed by simply specifying the type (`static_assert!((N: u32) N != 0)`).
+Alternatively, the `#[static_asserts]` attribute can fill in the generics list from the signature of the item.
 
 # Important #3
-Not declaring the generics present in the expression results in an error.
+On compilers older than 1.79, not declaring the generics present in the expression results in an error.
 
 ```
+# use static_assert_generic::*;
 fn bar<const N: usize>() {
-    static_assert!(() N != 0 => "N must be a non-zero value!");
-    // can't use generic parameters from outer item
+    static_assert!(() N != 0 => "N must be a non-zero value!");
+    // before 1.79: can't use generic parameters from outer item
 }
 ```
 
 # Important #4
-If a type generic that is `?Sized` gets passed in, it will result in an error:
+On compilers older than 1.79, if a type generic that is `?Sized` gets passed in, it will result in an error:
 
 ```
+# use static_assert_generic::*;
 fn foo<T: ?Sized>() {
-    static_assert!((T) ...);
-    // the associated item `CHECK` exists for struct `Assert<T>`, but its trait bounds were not satisfied
+    static_assert!((T) std::mem::size_of::<*const T>() >= std::mem::size_of::<usize>());
+    // before 1.79: the associated item `CHECK` exists for struct `Assert<T>`, but its trait bounds were not satisfied
 }
 ```
 
@@ -79,403 +108,350 @@ Optionally sized type generics need to be specified using `?` (`static_assert!((
 # Examples
 
 Asserting constant expressions:
-```
+```compile_fail,E0080
+# use static_assert_generic::*;
 static_assert!(() 1 + 2 < 17); // True statement, compiles.
 
 static_assert!(() 45 * 25 < 3); // False statement, does not compile:
 
 // error[E0080]: evaluation of constant value failed
-//  |     static_assert!(() 45 * 25 < 3)
-//  |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'Static assert failed.'
+//  |     static_assert!(() 45 * 25 < 3)
+//  |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'static assertion failed: 45 * 25 < 3 (src/main.rs:3)'
+```
+Without a message, the failure reports the asserted expression, along with the file and line of the assertion.
+Expressions made up of `&&`, `||`, `!` and comparisons also report the clauses that failed,
+along with the values of compared operands whose type can be told (such as declared const generics):
+```compile_fail,E0080
+# use static_assert_generic::*;
+fn foo<const N: usize, const M: usize>() {
+    static_assert!((N: usize, M: usize) N > 0 && M < N && N % M == 0);
+}
+
+foo::<6, 4>();
+// the evaluated program panicked at 'static assertion failed: N > 0 && M < N && N % M == 0 (src/main.rs:2)
+// failed: N % M == 0
+//   left: 2
+//  right: 0'
 ```
 
 \
-An error message can be optionally specified:
+At module scope, `static_assert_items!` declares a list of such assertions as `const _` items:
+```
+# use static_assert_generic::*;
+# struct Header([u8; 48]);
+static_assert_items! {
+    std::mem::size_of::<usize>() == 8 => "Only 64-bit platforms are supported!",
+    std::mem::size_of::<Header>() <= 64,
+}
+```
+
+\
+Assertions can be given `#[cfg(...)]` attributes, in which case they're only checked if every one of the predicates holds:
+```
+# use static_assert_generic::*;
+fn foo<const N: usize>() {
+    static_assert!(#[cfg(feature = "strict")] (N: usize) N is power_of_two);
+}
+```
+Every assertion can also be turned off at once, by enabling the `off` feature of this crate, or building with `--cfg static_assert_generic_off`
+(such as through `RUSTFLAGS`). Assertions that aren't checked are still type-checked, so they don't rot while they're off.
+
+\
+Constraints that are only advisory can be checked with `static_warn!`, which emits a compiler warning instead of an error.
+Since warnings are emitted before generics are instantiated, the instantiations to check are listed like with `static_assert_instantiations!`,
+and placeholders in the message are replaced by their generic arguments, as written rather than evaluated:
+```
+# use static_assert_generic::*;
+# struct Header([u8; 48]);
+const _: () = static_warn!(() std::mem::size_of::<Header>() <= 64 => "Header no longer fits in a cache line.");
+
+static_warn!(for (N: usize) in [8, 16, 32] N >= 16 => "N = {N} is slow, and will be phased out.");
+// warning: use of deprecated associated constant `_::StaticWarning::<true, 0>::WARNING`: N = 8 is slow, and will be phased out.
 ```
+
+\
+Trait implementations are asserted with `assert_impl!`, whose error names the type and the trait that isn't implemented.
+It's checked while type-checking, so `cargo check` reports it as well (the messages need Rust 1.78 or newer):
+```compile_fail,E0277
+# use static_assert_generic::*;
+# struct Handle;
+assert_impl!(Handle: Send + Sync + 'static);
+assert_impl!((T: Send) Vec<T>: Send);
+
+assert_impl!(std::rc::Rc<u8>: Send);
+// error[E0277]: `std::rc::Rc<u8>` doesn't implement `Send`
+```
+
+\
+`assert_not_impl!` asserts the opposite, such as guards that need to stay on one thread not implementing `Send`.
+`any(...)` asserts that none of its traits are implemented, and `all(...)` that at least one of them isn't:
+```compile_fail,E0277
+# use static_assert_generic::*;
+# struct Guard(std::marker::PhantomData<*const ()>);
+assert_not_impl!(Guard: any(Send, Sync, Clone));
+assert_not_impl!((T) std::cell::Cell<T>: all(Sync, Copy));
+
+assert_not_impl!(u8: any(Send, Copy));
+// error[E0277]: `u8` implements `Send`
+```
+
+\
+An error message can be optionally specified:
+```compile_fail,E0080
+# use static_assert_generic::*;
 static_assert!(() 45 * 25 < 3 => "This is the error message!");
 // the evaluated program panicked at 'This is the error message!'
 ```
 
+\
+The message can be followed by `help:` and `note:` lines:
+```compile_fail,E0080
+# use static_assert_generic::*;
+fn foo<const N: usize>() {
+    static_assert!((N: usize) N != 0 => "N must be non-zero!", help: "use `foo_empty` instead", note: "N is used as a divisor");
+}
+
+foo::<0>();
+// the evaluated program panicked at 'N must be non-zero!
+// help: use `foo_empty` instead
+// note: N is used as a divisor'
+```
+
+\
+The values of declared const generics (of integer, `bool` or `char` type) can be interpolated into the message:
+```compile_fail,E0080
+# use static_assert_generic::*;
+fn foo<const N: usize>() {
+    static_assert!((N: usize) N <= 64 => "N must be <= 64, got {N}");
+}
+
+foo::<100>(); // the evaluated program panicked at 'N must be <= 64, got 100'
+```
+
+\
+Common checks on declared const generics of integer type can be written as predicates, which come with a tailored message:
+```compile_fail,E0080
+# use static_assert_generic::*;
+fn foo<const N: usize, T>() {
+    static_assert!((N: usize, T) {
+        N in 1..=64,
+        N in [1, 2, 4, 8, 16, 32, 64],
+        N is power_of_two,
+        N is multiple_of(std::mem::align_of::<T>()),
+        N fits u16,
+    });
+}
+
+foo::<100, u8>(); // the evaluated program panicked at 'N = 100 is not in 1..=64'
+```
+Any kind of range can follow `in`, and a message can be given to a predicate like to any other check.
+
 \
 Pass in const generics using `identifier: type` syntax:
 ```
+# use static_assert_generic::*;
 fn foo<const C: u32>() {
-    static_assert!((C: u32) C > 3 => "C must be greater than 3!");
+    static_assert!((C: u32) C > 3 => "C must be greater than 3!");
 }
 ```
 
 \
 Type generics can be used as well.
 ```
+# use static_assert_generic::*;
 fn baz<T>() {
-    static_assert!((T) std::mem::size_of::<T>() == 4 => "T must be 4 bytes long!");
+    static_assert!((T) std::mem::size_of::<T>() == 4 => "T must be 4 bytes long!");
+}
+```
+
+\
+Type generics can be given trait bounds, so that their associated constants can be used:
+```
+# use static_assert_generic::*;
+# trait HasCapacity { const CAPACITY: usize; }
+fn baz<T: HasCapacity, const N: usize>() {
+    static_assert!((T: HasCapacity, N: usize) T::CAPACITY >= N => "T can't hold N elements!");
+}
+```
+Since const generics are declared with the same syntax, a type after `:` that const generics can have (integers, `bool` and `char`)
+makes the generic a const one, while anything else is taken as trait bounds.
+Const generics whose type is spelled differently, such as through a type alias, are declared with `const` like in Rust:
+```
+# use static_assert_generic::*;
+type Idx = usize;
+
+fn baz<const N: Idx>() {
+    static_assert!((const N: Idx) N != 0 => "N must be non-zero!");
 }
 ```
 
 \
 Unsized types need to be passed with this syntax:
 ```
+# use static_assert_generic::*;
 fn baz<U: ?Sized>() {
-    static_assert!((U?) true => "There isn't much you can statically check about unsized types.");
+    static_assert!((U?) true => "There isn't much you can statically check about unsized types.");
 }
 ```
+Unsized types can be given trait bounds as well (`static_assert!((U?: Debug) ...)`).
 
 \
-Multiple generics can be used at a time.
+Bounds that can't be written on the generics themselves go in a `where` clause following the generics list, terminated by a `;`:
+```
+# use static_assert_generic::*;
+# trait Codec { const SIZE: usize; }
+fn baz<T: IntoIterator>() where T::Item: Codec {
+    static_assert!((T: IntoIterator) where T::Item: Codec; <T::Item as Codec>::SIZE <= 8 => "Items of T are too large!");
+}
+```
+
+\
+Lifetimes can be declared as well, in which case the declared types are assumed to outlive them:
+```
+# use static_assert_generic::*;
+fn baz<'a, T>(x: &'a T) {
+    static_assert!((T, 'a) std::mem::size_of::<&'a T>() == 8 => "References to T must be thin!");
+}
 ```
+
+\
+Multiple generics can be used at a time.
+```compile_fail,E0080
+# use static_assert_generic::*;
 fn baz<const N: usize, const M: usize, T>() {
-    static_assert!((N: usize, M: usize) N > M => "N must be greater than M!");
-    static_assert!((N: usize, T) N == std::mem::size_of::<T>() / 2 => "N must be half the size of T!");
+    static_assert!((N: usize, M: usize) N > M => "N must be greater than M!");
+    static_assert!((N: usize, T) N == std::mem::size_of::<T>() / 2 => "N must be half the size of T!");
 }
 
 baz::<4, 7, u64>(); // panics at "N must be greater than M!"
 baz::<4, 1, u8>(); // panics at "N must be half the size_of T!"
 ```
-*/
 
-enum Generic {
-    Type(syn::Ident),
-    UnsizedType(syn::Ident),
-    Const(syn::Ident, syn::Type),
+\
+Multiple checks using the same generics can be grouped into a block, each with its own message:
+```
+# use static_assert_generic::*;
+fn baz<const N: usize, T>() {
+    static_assert!((N: usize, T) {
+        N > 0 => "N must be non-zero!",
+        N % 2 == 0 => "N must be even!",
+        std::mem::size_of::<T>() <= N => "T must fit in N bytes!",
+    });
 }
+```
 
-impl Generic {
-    pub fn definition(&self) -> proc_macro2::TokenStream {
-        match self {
-            Generic::Type(i) => quote::quote! { #i, },
-            Generic::UnsizedType(i) => quote::quote! { #i: ?Sized, },
-            Generic::Const(i, t) => quote::quote! { const #i: #t, },
-        }
-    }
+\
+Instead of the parenthesized syntax, generics can also be copied verbatim from a signature, in angle brackets:
+```
+# use static_assert_generic::*;
+# use std::fmt::Debug;
+fn baz<'a, const N: usize, T: ?Sized + Debug + 'a>(x: &'a T) {
+    static_assert!(<'a, const N: usize, T: ?Sized + Debug + 'a> N <= std::mem::size_of::<&'a T>());
+}
+```
+Defaults are ignored, and unlike the parenthesized syntax, types aren't assumed to outlive every declared lifetime,
+only those they're referenced with in the assertion (`T: 'a` for `&'a T`), since copied signatures often have unrelated lifetimes.
 
-    pub fn placement(&self) -> proc_macro2::TokenStream {
-        match self {
-            Generic::Type(i) => quote::quote! { #i, },
-            Generic::UnsizedType(i) => quote::quote! { #i, },
-            Generic::Const(i, _t) => quote::quote! { #i, },
-        }
-    }
+\
+`generic_const!` evaluates a value from generics at compile-time, optionally only if a predicate holds:
+```
+# use static_assert_generic::*;
+fn baz<const N: usize, T>() {
+    let len = generic_const!((N: usize, T) -> usize N * std::mem::size_of::<T>() if N <= 64 => "N = {N} is too large!");
+}
+```
 
-    pub fn placement_type(&self) -> Option<proc_macro2::TokenStream> {
-        match self {
-            Generic::Type(_i) => Some(self.placement()),
-            Generic::UnsizedType(_i) => Some(self.placement()),
-            Generic::Const(_i, _t) => None,
-        }
-    }
+\
+Naming a proposition type after `->` makes the assertion evaluate to zero-sized [`Checked`] evidence of it,
+so that a requirement checked once shows up in the signatures of the functions relying on it:
+```
+# use static_assert_generic::*;
+enum NonZero<const N: usize> {}
+
+fn chunks<const N: usize>(data: &[u8]) -> usize {
+    let proof: Checked<NonZero<N>> = static_assert!((N: usize) -> NonZero<N> N != 0 => "N must be non-zero");
+    count::<N>(data, proof)
 }
 
-impl syn::parse::Parse for Generic {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        match input.parse() {
-            Ok(ident) => {
-                Ok(if input.parse::<syn::Token![:]>().is_ok() {
-                    if let Ok(qm) = input.parse::<syn::Token![?]>() {
-                        return Err(syn::Error::new(qm.span, format!("Syntax error, if you want to make the type unsized do {ident}? instead of {ident}: ?Sized.")))
-                    }
-                    Generic::Const(ident, input.parse()?)
-                } else if input.parse::<syn::Token![?]>().is_ok() {
-                    Generic::UnsizedType(ident)
-                } else {
-                    Generic::Type(ident)
-                })
-            }
-            Err(err) => {
-                Err(if let Ok(const_token) = input.parse::<syn::Token![const]>() {
-                    syn::Error::new(const_token.span, 
-                        "Expected identifier, got keyword `const` instead. If you meant to declare a const generic, the syntax is just [identifier]: [type], without `const`.")
-                } else {
-                    err
-                })
-            }
-        }
-        
-    }
+fn count<const N: usize>(data: &[u8], _: Checked<NonZero<N>>) -> usize {
+    data.len() / N
 }
+```
+The proposition can also be left to inference (`static_assert!((N: usize) -> _ N != 0)`).
+Evidence is only produced by assertions that are always checked: `->` can't be combined with `#[cfg(...)]` attributes,
+and producing evidence fails to compile while assertions are turned off.
 
-struct StaticAssertInput {
-    generics: Vec<Generic>,
-    expression: syn::Expr,
-    message: Option<proc_macro2::TokenStream>,
+\
+Invariants of a type's generics can be declared once on the type with `#[derive(StaticInvariants)]`,
+and are checked by calling the generated `Self::__check()` from its constructors:
+```
+# use static_assert_generic::*;
+#[derive(StaticInvariants)]
+#[invariant(N > 0 && N <= 4096, "capacity out of range")]
+struct RingBuf<T, const N: usize> {
+    buf: [Option<T>; N],
 }
 
-impl syn::parse::Parse for StaticAssertInput {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        Ok(StaticAssertInput {
-            generics: {
-                let generics_buf;
-                syn::parenthesized!(generics_buf in input);
-                generics_buf.parse_terminated(Generic::parse, syn::Token![,])?.into_iter().collect()
-            },
-            expression: input.parse()?,
-            message: if input.parse::<syn::Token![=>]>().is_ok() { Some(input.parse()?) } else { None },
-        })
+impl<T, const N: usize> RingBuf<T, N> {
+    fn new() -> Self {
+        Self::__check();
+        RingBuf { buf: [const { None }; N] }
     }
 }
+```
+
+\
+Putting `#[static_asserts]` on a `fn` item or `impl` block makes the generics list optional inside of it:
+```
+# use static_assert_generic::*;
+#[static_asserts]
+fn baz<const N: usize, T>() {
+    static_assert!(N == std::mem::size_of::<T>() / 2 => "N must be half the size of T!");
+}
+```
 
-/// The main use-case for this crate.\
-/// Macro for asserting statements at compile-time, with the possibility of passing in generics as well.
-/// Refer to to the crate-level documentation for more information.
-#[proc_macro]
-pub fn static_assert(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-
-    let StaticAssertInput { generics, expression, message } = syn::parse_macro_input!(input as StaticAssertInput);
-
-    let generic_definitions: proc_macro2::TokenStream = generics.iter().map(Generic::definition).collect();
-    let generic_placement: proc_macro2::TokenStream = generics.iter().map(Generic::placement).collect();
-    let generic_placement_types: Vec<proc_macro2::TokenStream> = generics.iter().filter_map(Generic::placement_type).collect();
-
-    quote::quote! {
-        _ = {
-            struct Assert<#generic_definitions>(#(core::marker::PhantomData<#generic_placement_types>),*);
-            impl<#generic_definitions> Assert<#generic_placement> {
-                #[allow(unused)]
-                const CHECK: () = if !(#expression) { panic!(#message) };
-            }
-            Assert::<#generic_placement>::CHECK
-        }
-    }.into()
+\
+`#[requires(...)]` states a requirement of a `fn` item or of every method of an `impl` block, asserting it at the start of their bodies
+and listing it in a "Compile-time requirements" section of the item's docs:
+```
+# use static_assert_generic::*;
+#[requires(N != 0, "N must be non-zero")]
+#[requires(N is power_of_two)]
+pub fn split<const N: usize>(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    data.chunks(N)
 }
+```
 
+\
+Comparisons can be asserted with `static_assert_eq!`, `static_assert_ne!`, `static_assert_lt!`, `static_assert_le!`,
+`static_assert_gt!` and `static_assert_ge!`, which report the values of both operands on failure:
+```compile_fail,E0080
+# use static_assert_generic::*;
+fn baz<const N: usize, T>() {
+    static_assert_eq!((N: usize, T) N, std::mem::size_of::<T>() / 2 => "N must be half the size of T!");
+}
 
+baz::<4, u8>();
+// the evaluated program panicked at 'assertion `left == right` failed: N must be half the size of T!
+//   left: 4
+//  right: 0'
+```
+*/
 
+mod checked;
+mod fmt;
+mod predicate;
 
+pub use checked::Checked;
+pub use static_assert_generic_macros::*;
 
-// This macro attempts to allow for making constants based off const generics. However, this does not work. 
-// fn foo<const A: u32>() {
-//     const B: u32 = generic_expr((A: u32) -> u32 A * 2);
-// }
-// 
-// Using it for non-constants is useless since they chould be done anyway:
-// fn foo<const A: u32>() {
-//     let b = A * 2;
-// }
-
-// struct GenericExprInput {
-//     generics: Vec<Generic>,
-//     return_type: syn::Type,
-//     expression: syn::Expr,
-// }
-
-// impl syn::parse::Parse for GenericExprInput {
-//     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-//         Ok(GenericExprInput {
-//             generics: {
-//                 let generics_buf;
-//                 syn::parenthesized!(generics_buf in input);
-//                 generics_buf.parse_terminated(Generic::parse, syn::Token![,])?.into_iter().collect()
-//             },
-//             return_type: {
-//                 input.parse::<syn::Token![->]>()?;
-//                 input.parse()?
-//             },
-//             expression: input.parse()?,
-//         })
-//     }
-// }
-
-// #[proc_macro]
-// pub fn generic_expr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-
-//     let GenericExprInput { generics, return_type, expression } = syn::parse_macro_input!(input as GenericExprInput);
-
-//     let generic_definitions: proc_macro2::TokenStream = generics.iter().map(Generic::definition).collect();
-//     let generic_placement: proc_macro2::TokenStream = generics.iter().map(Generic::placement).collect();
-//     let generic_placement_types: Vec<proc_macro2::TokenStream> = generics.iter().filter_map(Generic::placement_type).collect();
-
-//     quote::quote! {
-//         {
-//             struct GenericExpr<#generic_definitions>(#(core::marker::PhantomData<#generic_placement_types>),*);
-//             impl<#generic_definitions> GenericExpr<#generic_placement> {
-//                 #[allow(unused)]
-//                 const VALUE: #return_type = #expression;
-//             }
-//             GenericExpr::<#generic_placement>::VALUE
-//         }
-//     }.into()
-
-// }
-
-
-
-/// Experimental macro that forces variables of a certain type to not have their destructor run.\
-/// However, it also has some serious drawbacks, meaning that you should likely refrain from using it in any serious project.
-/// Its primary use case is being able to drop objects that require updating other structures:
-/// 
-/// ```
-/// impl<T> Foo<T> {
-///     explicitly_drop!(T => "MyStruct must be dropped explicitly!");
-/// }
-/// ```
-/// 
-/// To prevent constant evaluation from always happening, the functionality is dependant on at least one generic (be it type or const)
-/// from the type it's implemented in, so if the type in question doesn't have that the macro won't work:
-/// 
-/// ```
-/// impl<T> Drop for Foo<T> {
-///     explicitly_drop!(T => "Dependant on type generic");
-/// }
-/// 
-/// impl<T: ?Sized> Drop for Foo<T> {
-///     explicitly_drop!(T? => "If the type is unsized it needs special syntax");
-/// }
-/// 
-/// impl<const C: u8> Drop for Foo<{C}> {
-///     explicitly_drop!(C: u8 => "Dependant on const generic (specifying the type is needed)");
-/// }
-/// 
-/// impl<const C: u8, const D: u16, T, U, V> Drop for Foo<{C}, {D}, T, U, V> {
-///     explicitly_drop!(C: u8 => "Just one is needed, even if the type has more.");
-/// }
-/// ```
-/// 
-/// Using a lifetime as a generic doesn't work.
-/// 
-/// # Example:
-/// 
-/// Consider a situation like this, where multiple allocators may be present at a time:\
-/// 
-/// ```
-/// struct Allocator {
-///     ...
-/// }
-///
-/// impl Allocator {
-///     pub fn alloc<T>(&mut self) -> Allocation<T> {
-///         todo!()
-///     }
-///
-///     pub fn free<T>(&mut self, allocation: Allocation<T>) {
-///         // ...
-///     }
-/// }
-///
-/// struct Allocation<T> {
-///     ptr_to_allocation: *const T
-/// }
-/// ```
-/// 
-/// Idealy, when the `Allocation` runs out of scope, it would be freed by the same `Allocator` that allocated it.
-/// However, implementing such `Drop` functionality would require the allocation to also hold some kind of reference to said `Allocator`.
-/// This would double its size and may become an issue if `Allocation` appears often.
-/// Still, if the programmer forgets to free the `Allocation` a memory leak would take place.
-///
-/// Using `explicitly_drop!` would give a compile-time error if `Allocation`'s `drop` method appears anywhere in the code:
-/// 
-/// ```
-/// impl<T> Drop for Allocation<T> {
-///     explicitly_drop!(T => "Allocation must be freed explicitly!");
-/// }
-/// ```
-/// 
-/// The `free` method would have to make sure that `Allocation`'s `drop` method doesn't appear either.
-/// 
-/// ```
-/// pub fn free<T>(&mut self, allocation: Allocation<T>) {
-///     let allocation = std::mem::ManuallyDrop::new(allocation);
-///     // ...
-/// }
-/// ```
-/// 
-/// Now if someone forgets to `free` an `Allocation`, the compiler will give an error 
-/// (just like `static_assert!`, this isn't caught by `cargo check`, and a full build is needed instead):
-/// 
-/// ```
-/// fn foo(allocator: Allocator) {
-///     let allocation: Allocation::<Whatever> = allocator.alloc();
-///     // ... allocation is not freed
-///     // allocation's drop method appears
-/// }
-/// 
-/// foo(my_allocator);
-/// 
-/// // error[E0080]: evaluation of `<Allocation<T> as std::ops::Drop>::drop::Assert::<Whatever>::MANUAL_DROP` failed
-/// //    |
-/// //    |         explicitly_drop!(T => "Allocation must be freed explicitly!");
-/// //    |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'Allocation must be freed explicitly!'
-/// //    |
-/// //
-/// // note: the above error was encountered while instantiating `fn <Allocation<Whatever> as std::ops::Drop>::drop`
-/// //    |
-/// //    | pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
-/// //    | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-/// ```
-/// 
-/// `free` (or prevent its `drop` method from appearing in any way) and the error dissapears:
-/// 
-/// ```
-/// fn foo(allocator: Allocator) {
-///     let allocation: Allocation::<Whatever> = allocator.alloc();
-///     // ... do something
-///     allocator.free(allocation);
-/// }
-/// 
-/// foo(my_allocator);
-/// 
-/// // compiles just fine
-/// ```
-/// 
-/// # Drawbacks
-/// 
-/// The drop method might appear even when it doesn't seem it should at first glance.
-/// For example, if a panic ever occours, all variables in scope, including those that need to be `explicitly_drop`ped, have their `drop` 
-/// method run, so even if the panic never occours at runtime, the simple appearance of `drop` will still cause a compile-time error:
-/// 
-/// ```
-/// fn bar(allocator: Allocator) {
-///     let allocation: Allocation::<Whatever> = allocator.alloc();
-///     if rand::random::<usize>() == 27 {
-///         panic!();
-///     }
-///     allocator.free(allocation);
-/// }
-/// 
-/// foo(my_allocator);
-/// 
-/// // ... 'Allocation must be freed explicitly!' ...
-/// ```
-/// 
-/// A huge amount of functionality in rust can result in panics. 
-/// Even if explicit `panic!`, `todo!`, or `unwrap`s are avoided, these operations, and many more, can also panic:
-/// - Indexing into a container without bounds checking.
-/// - Basically every unchecked heap allocation.
-/// - Any mathematical operation that can over/underflow (on a debug build).
-/// - Possible division or modulo operation by 0.
-/// 
-/// This method also assumes that rust optimises out, and as such doesn't attempt to evaluate 
-/// constants if the method they are in isn't use, which might not even always be the case.
-#[proc_macro]
-pub fn explicitly_drop(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    struct ExplicitlyDropInput {
-        generic: Generic,
-        message: Option<proc_macro2::TokenStream>,
-    }
-    
-    impl syn::parse::Parse for ExplicitlyDropInput {
-        fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-            Ok(ExplicitlyDropInput {
-                generic: input.parse()?,
-                message: if input.parse::<syn::Token![=>]>().is_ok() { Some(input.parse()?) } else { None },
-            })
-        }
-    }
+/// Items the macros expand into, shared by all of their expansions instead of being generated for each one.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::checked::checked;
+    pub use crate::fmt::{Kind, Message, Value};
+    pub use crate::predicate::fits;
 
-    let ExplicitlyDropInput { generic, message } = syn::parse_macro_input!(input as ExplicitlyDropInput);
-
-    let generic_definition = generic.definition();
-    let generic_placement = generic.placement();
-    let phantomdatas = generic.placement_type()
-        .map(|x| quote::quote! { (core::marker::PhantomData<#x>) });
-
-    quote::quote! {
-        fn drop(&mut self) {
-            _ = {
-                struct Assert<#generic_definition>#phantomdatas;
-                impl<#generic_definition> Assert<#generic_placement> {
-                    const MANUAL_DROP: () = panic!(#message);
-                }
-                Assert::<#generic_placement>::MANUAL_DROP
-            };
-        }
-    }.into()
-}
\ No newline at end of file
+    /// Whether assertions are checked, unless they're turned off by the `off` feature or `--cfg static_assert_generic_off`.
+    pub const ENABLED: bool = !cfg!(any(feature = "off", static_assert_generic_off));
+}
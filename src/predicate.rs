@@ -0,0 +1,15 @@
+//! Const-evaluable checks behind the predicates of `static_assert!` that can't be written as plain expressions.
+
+use crate::fmt::{magnitude, Kind, Value};
+
+/// Whether an integer `value` can be represented by the integer type `U` (as in `N fits u16`).
+pub const fn fits<U: Value, T: Value>(value: T) -> bool {
+    let (negative, magnitude) = magnitude(value);
+    let bits = core::mem::size_of::<U>() as u32 * 8;
+    match U::KIND {
+        Kind::Unsigned => !negative && (bits == 128 || magnitude >> bits == 0),
+        Kind::Signed if negative => magnitude <= 1 << (bits - 1),
+        Kind::Signed => magnitude < 1 << (bits - 1),
+        Kind::Bool | Kind::Char => false,
+    }
+}
@@ -0,0 +1,172 @@
+//! Builds every fixture in `tests/compile_fail` with the local toolchain, and checks that it fails with the expected errors.
+//! Fixtures are built both with the inline `const` expansion (on Rust 1.79 and newer) and with the `Assert` struct expansion,
+//! which newer compilers are forced to use by `--cfg static_assert_generic_no_inline_const`.
+//!
+//! Fixtures start with directives in line comments:
+//! - `// error-pattern: text` needs `text` to appear in the output of the build, and can be repeated.
+//! - `// warning-pattern: text` does the same, but needs the build to succeed if there are no error patterns, for fixtures checking warnings.
+//! - `// build-pass` needs the build to succeed, without needing any patterns.
+//! - `// expansion: struct` or `// expansion: inline-const` only builds the fixture with the given expansion.
+//! - `// rustflags: --cfg name` builds the fixture with the given `RUSTFLAGS`.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+struct Fixture {
+    name: String,
+    patterns: Vec<String>,
+    /// Whether the build is expected to succeed, with only warnings.
+    builds: bool,
+    expansion: Option<Expansion>,
+    rustflags: Option<String>,
+}
+
+/// The ways assertions can be expanded, which report errors differently.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Expansion {
+    /// Inline `const { }` blocks, on Rust 1.79 and newer.
+    InlineConst,
+    /// An `Assert` struct, the only expansion on older compilers.
+    Struct,
+}
+
+impl Expansion {
+    fn rustflags(self) -> &'static str {
+        match self {
+            Expansion::InlineConst => "",
+            Expansion::Struct => "--cfg static_assert_generic_no_inline_const",
+        }
+    }
+}
+
+impl Fixture {
+    fn parse(path: &Path) -> Fixture {
+        let source = std::fs::read_to_string(path).unwrap();
+        let mut fixture = Fixture { name: path.file_stem().unwrap().to_str().unwrap().to_string(), patterns: Vec::new(), builds: true, expansion: None, rustflags: None };
+        let mut build_pass = false;
+        for line in source.lines().map_while(|line| line.strip_prefix("// ")) {
+            if let Some(pattern) = line.strip_prefix("error-pattern: ") {
+                fixture.patterns.push(pattern.to_string());
+                fixture.builds = false;
+            } else if let Some(pattern) = line.strip_prefix("warning-pattern: ") {
+                fixture.patterns.push(pattern.to_string());
+            } else if line == "build-pass" {
+                build_pass = true;
+            } else if let Some(rustflags) = line.strip_prefix("rustflags: ") {
+                fixture.rustflags = Some(rustflags.to_string());
+            } else if let Some(expansion) = line.strip_prefix("expansion: ") {
+                fixture.expansion = Some(match expansion {
+                    "struct" => Expansion::Struct,
+                    "inline-const" => Expansion::InlineConst,
+                    _ => panic!("{}: invalid expansion `{expansion}`", fixture.name),
+                });
+            }
+        }
+        assert!(!fixture.patterns.is_empty() || build_pass, "{}: expected at least one `// error-pattern:` or `// warning-pattern:`", fixture.name);
+        fixture
+    }
+}
+
+/// The minor version out of a `1.xx` version.
+fn minor_version(version: &str) -> Option<u32> {
+    version.split('.').nth(1)?.split(|c: char| !c.is_ascii_digit()).next()?.parse().ok()
+}
+
+fn rustc_minor_version() -> u32 {
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = Command::new(rustc).arg("--version").output().unwrap();
+    minor_version(std::str::from_utf8(&output.stdout).unwrap()).unwrap()
+}
+
+/// Sets up a package named `name` depending on this crate, with the given files copied into it (such as `src/bin/fixture.rs`).
+fn package(name: &str, files: &[(PathBuf, PathBuf)]) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    for subdir in ["src", "tests"] {
+        _ = std::fs::remove_dir_all(dir.join(subdir));
+    }
+    for (from, to) in files {
+        let to = dir.join(to);
+        std::fs::create_dir_all(to.parent().unwrap()).unwrap();
+        std::fs::copy(from, to).unwrap();
+    }
+
+    std::fs::write(dir.join("Cargo.toml"), format!(r#"
+[package]
+name = "{name}"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[dependencies]
+static_assert_generic = {{ path = {:?} }}
+
+[workspace]
+"#, env!("CARGO_MANIFEST_DIR"))).unwrap();
+    dir
+}
+
+/// A cargo command run in the package at `dir` with the given expansion, using a target directory of its own for each expansion
+/// so that switching between them doesn't rebuild everything.
+fn cargo(dir: &Path, expansion: Expansion, rustflags: Option<&str>) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    let rustflags = [expansion.rustflags(), rustflags.unwrap_or_default()].join(" ");
+    command.current_dir(dir)
+        .env("CARGO_TARGET_DIR", dir.join("target").join(format!("{expansion:?}")))
+        .env("RUSTFLAGS", rustflags.trim());
+    command
+}
+
+/// The expansions supported by the local toolchain.
+fn expansions() -> Vec<Expansion> {
+    match rustc_minor_version() {
+        79.. => vec![Expansion::InlineConst, Expansion::Struct],
+        _ => vec![Expansion::Struct],
+    }
+}
+
+#[test]
+fn compile_fail() {
+    let mut fixtures: Vec<PathBuf> = std::fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compile_fail")).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect();
+    fixtures.sort();
+
+    let files: Vec<(PathBuf, PathBuf)> = fixtures.iter().map(|path| (path.clone(), Path::new("src/bin").join(path.file_name().unwrap()))).collect();
+    let dir = package("compile_fail", &files);
+
+    let mut failures = Vec::new();
+    for expansion in expansions() {
+        for fixture in fixtures.iter().map(|path| Fixture::parse(path)) {
+            if fixture.expansion.is_some_and(|only| only != expansion) {
+                continue;
+            }
+
+            let output = cargo(&dir, expansion, fixture.rustflags.as_deref())
+                .args(["build", "--offline", "--color", "never", "--bin", &fixture.name])
+                .output().unwrap();
+            let stderr = String::from_utf8_lossy(&output.stderr);
+
+            if output.status.success() != fixture.builds {
+                let outcome = if fixture.builds { "failed to compile" } else { "compiled successfully" };
+                failures.push(format!("{} ({expansion:?}): {outcome}:\n{stderr}", fixture.name));
+            } else if let Some(pattern) = fixture.patterns.iter().find(|pattern| !stderr.contains(pattern.as_str())) {
+                failures.push(format!("{} ({expansion:?}): expected `{pattern}` in the output:\n{stderr}", fixture.name));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+/// Runs `tests/tests.rs` with the `Assert` struct expansion, which `cargo test` only covers on compilers older than 1.79.
+#[test]
+fn tests_with_struct_expansion() {
+    let tests = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/tests.rs");
+    let dir = package("struct_expansion", &[(tests, PathBuf::from("tests/tests.rs"))]);
+
+    let output = cargo(&dir, Expansion::Struct, None)
+        .args(["test", "--offline", "--color", "never", "--test", "tests"])
+        .output().unwrap();
+    assert!(output.status.success(), "{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+}
@@ -0,0 +1,18 @@
+// error-pattern: `(N)` followed by `-` could be either a generics list or the start of the condition.
+// error-pattern: `(N, M)` followed by `*` could be either a generics list or the start of the condition.
+
+use static_assert_generic::*;
+
+#[static_asserts]
+fn foo<const N: usize>() {
+    static_assert!((N) - 1 < 0);
+}
+
+fn bar<const N: usize, const M: usize>() {
+    static_assert!((N, M) * &1 > 0);
+}
+
+fn main() {
+    foo::<3>();
+    bar::<1, 2>();
+}
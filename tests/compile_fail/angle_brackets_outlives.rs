@@ -0,0 +1,18 @@
+// build-pass
+
+use static_assert_generic::*;
+
+fn foo<'a, T>(x: &'a T) -> &'a T {
+    static_assert!(<'a, T> std::mem::size_of::<&'a T>() == std::mem::size_of::<usize>());
+    x
+}
+
+fn bar<'a, 'b, T, U: ?Sized>(x: &'a T, _: &'b U, _: &'a u8) -> &'a T {
+    static_assert!(<'a, 'b, T, U: ?Sized> std::mem::size_of::<&'a T>() <= std::mem::size_of::<&'b U>());
+    x
+}
+
+fn main() {
+    foo(&1u8);
+    bar(&1u8, "str", &0);
+}
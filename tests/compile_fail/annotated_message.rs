@@ -0,0 +1,18 @@
+// error-pattern: error[E0080]
+// error-pattern: N = 0 must be non-zero!
+// error-pattern: help: use `foo_empty` instead
+// error-pattern: note: N is used as a divisor
+// error-pattern: assertion `left > right` failed: N must be greater than 2
+// error-pattern: right: 2
+// error-pattern: help: N = 0 is too small
+
+use static_assert_generic::*;
+
+fn foo<const N: usize>() {
+    static_assert!((N: usize) N != 0 => "N = {N} must be non-zero!", help: "use `foo_empty` instead", note: "N is used as a divisor");
+    static_assert_gt!((N: usize) N, 2 => "N must be greater than 2", help: "N = {N} is too small");
+}
+
+fn main() {
+    foo::<0>();
+}
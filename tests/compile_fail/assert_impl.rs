@@ -0,0 +1,18 @@
+// error-pattern: `Handle` doesn't implement `Send`
+// error-pattern: asserted to implement `Send`
+// error-pattern: `Vec<T>` doesn't implement `Sync`
+// error-pattern: `Cell<u8>` doesn't implement `Copy`
+// error-pattern: handles need to be shareable
+
+use static_assert_generic::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct Handle(Rc<u8>);
+
+assert_impl!(Handle: Send);
+assert_impl!((T: Send) Vec<T>: Send + Sync);
+assert_impl!(Cell<u8>: Copy + Clone + Sync);
+assert_impl!(Handle: Sync => "handles need to be shareable");
+
+fn main() {}
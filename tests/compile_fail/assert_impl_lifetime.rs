@@ -0,0 +1,7 @@
+// error-pattern: borrowed data escapes outside of function
+
+use static_assert_generic::*;
+
+assert_impl!(('a) &'a str: Send + 'static);
+
+fn main() {}
@@ -0,0 +1,18 @@
+// error-pattern: `u8` implements `Send`
+// error-pattern: asserted not to implement `Send`
+// error-pattern: `u8` implements `Copy`
+// error-pattern: `Vec<T>` implements `all(Send, Default)`
+// error-pattern: `Handle` implements `all(Send, any(Sync, all(Send, Clone)))`
+// error-pattern: handles must stay on their thread
+
+use static_assert_generic::*;
+
+#[derive(Clone)]
+struct Handle(u8);
+
+assert_not_impl!(u8: any(Send, Copy));
+assert_not_impl!((T: Send) Vec<T>: all(Send, Default));
+assert_not_impl!(Handle: all(Send, any(Sync, all(Send, Clone))));
+assert_not_impl!(Handle: Clone => "handles must stay on their thread");
+
+fn main() {}
@@ -0,0 +1,32 @@
+// error-pattern: error[E0080]
+// error-pattern: T = u8 has no capacity!
+// error-pattern: 8 elements exceed the capacity of T
+
+use static_assert_generic::*;
+
+trait HasCapacity {
+    const CAPACITY: usize;
+}
+
+impl HasCapacity for u8 {
+    const CAPACITY: usize = 0;
+}
+
+impl HasCapacity for u16 {
+    const CAPACITY: usize = 4;
+}
+
+fn foo<T: HasCapacity + Copy>() {
+    static_assert!((T: HasCapacity + Copy) T::CAPACITY > 0 => "T = u8 has no capacity!");
+}
+
+#[static_asserts]
+fn bar<T: HasCapacity, const N: usize>() {
+    static_assert!(N <= T::CAPACITY => "{N} elements exceed the capacity of T");
+}
+
+fn main() {
+    foo::<u8>();
+    foo::<u16>();
+    bar::<u16, 8>();
+}
@@ -0,0 +1,14 @@
+// error-pattern: N must be non-zero in debug builds!
+// error-pattern: assertion `left == right` failed
+
+use static_assert_generic::*;
+
+fn foo<const N: usize>() {
+    static_assert!(#[cfg(debug_assertions)] (N: usize) N != 0 => "N must be non-zero in debug builds!");
+    static_assert!(#[cfg(not(debug_assertions))] (N: usize) N == 1 => "N must be one in release builds!");
+    static_assert_eq!(#[cfg(debug_assertions)] #[cfg(target_pointer_width = "64")] (N: usize) N, 1);
+}
+
+fn main() {
+    foo::<0>();
+}
@@ -0,0 +1,12 @@
+// error-pattern: Only 64-bit platforms are supported!
+
+use static_assert_generic::*;
+
+static_assert_items! {
+    #[cfg(not(debug_assertions))]
+    std::mem::size_of::<usize>() == 2 => "Only 16-bit platforms are supported!",
+    #[cfg(target_pointer_width = "64")]
+    std::mem::size_of::<usize>() == 4 => "Only 64-bit platforms are supported!",
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// error-pattern: error[E0080]
+// error-pattern: assertion `left > right` failed: N must be greater than M!
+// error-pattern: assertion `left == right` failed
+
+use static_assert_generic::*;
+
+fn foe<const N: usize, const M: usize, T>() {
+    static_assert_gt!((N: usize, M: usize) N, M => "N must be greater than M!");
+    static_assert_eq!((N: usize, T) N, std::mem::size_of::<T>() / 2);
+}
+
+fn main() {
+    foe::<4, 7, u64>();
+    foe::<4, 1, u8>();
+}
@@ -0,0 +1,20 @@
+// build-pass
+
+use static_assert_generic::*;
+
+type Idx = usize;
+
+fn foo<const N: Idx>() {
+    static_assert!((const N: Idx) N != 0 => "N must be non-zero, got {N}");
+    static_assert!((const N: Idx) N is power_of_two);
+}
+
+#[static_asserts]
+fn bar<const N: Idx, T>() {
+    static_assert!(N <= std::mem::size_of::<T>() => "T is too small for N = {N}");
+}
+
+fn main() {
+    foo::<4>();
+    bar::<4, u32>();
+}
@@ -0,0 +1,11 @@
+// error-pattern: declare others with `const N: Type`
+
+use static_assert_generic::*;
+
+fn foo<const N: usize>() {
+    static_assert!((N: [u8; 2]) N != 0);
+}
+
+fn main() {
+    foo::<1>();
+}
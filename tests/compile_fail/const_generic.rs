@@ -0,0 +1,14 @@
+// error-pattern: error[E0080]
+// error-pattern: N must be a non-zero value!
+// error-pattern: fn foo::<0>
+
+use static_assert_generic::*;
+
+fn foo<const N: usize>() {
+    static_assert!((N: usize) N != 0 => "N must be a non-zero value!");
+}
+
+fn main() {
+    foo::<12>();
+    foo::<0>();
+}
@@ -0,0 +1,8 @@
+// error-pattern: error[E0080]
+// error-pattern: static assertion failed: 45 * 25 < 3 (src/bin/constant.rs:7)
+
+use static_assert_generic::*;
+
+fn main() {
+    static_assert!(() 45 * 25 < 3);
+}
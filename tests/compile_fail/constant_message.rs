@@ -0,0 +1,8 @@
+// error-pattern: error[E0080]
+// error-pattern: This is the error message!
+
+use static_assert_generic::*;
+
+fn main() {
+    static_assert!(() 45 * 25 < 3 => "This is the error message!");
+}
@@ -0,0 +1,50 @@
+// error-pattern: static assertion failed: N > 0 && M < N && N % M == 0 (src/bin/decomposed.rs:
+// error-pattern: failed: N % M == 0
+// error-pattern: left: 2
+// error-pattern: static assertion failed: M != 0 && N % M == 0
+// error-pattern: failed: M != 0
+// error-pattern: failed: N > 64
+// error-pattern: failed: N as u8 == 0
+// error-pattern: left: 6
+// error-pattern: failed: ! (N >= 1)
+// error-pattern: failed: T :: CAPACITY > 4
+// error-pattern: failed: units :: size_of :: < T > () == 3u32
+// error-pattern: right: 3
+
+use static_assert_generic::*;
+
+trait HasCapacity { const CAPACITY: usize; }
+impl HasCapacity for u8 { const CAPACITY: usize = 0; }
+
+fn foo<const N: usize, const M: usize>() {
+    static_assert!((N: usize, M: usize) N > 0 && M < N && N % M == 0);
+    static_assert!((N: usize, M: usize) M != 0 && N % M == 0);
+}
+
+fn bar<const N: usize>() {
+    static_assert!((N: usize) (N > 64 || N as u8 == 0) && N < 100);
+    static_assert!((N: usize) N == 0 || !(N >= 1));
+}
+
+fn baz<T: HasCapacity>() {
+    static_assert!((T: HasCapacity) T::CAPACITY == 0 && T::CAPACITY > 4);
+}
+
+mod units {
+    /// Not `core::mem::size_of`, so its result isn't a `usize`.
+    pub const fn size_of<T>() -> u32 {
+        std::mem::size_of::<T>() as u32 * 8
+    }
+}
+
+fn qux<T: HasCapacity>() {
+    static_assert!((T: HasCapacity) T::CAPACITY > 0 || units::size_of::<T>() == 3u32);
+}
+
+fn main() {
+    foo::<6, 4>();
+    foo::<6, 0>();
+    bar::<6>();
+    baz::<u8>();
+    qux::<u8>();
+}
@@ -0,0 +1,28 @@
+// error-pattern: error[E0080]
+// error-pattern: static assertion failed: N != 0 (src/bin/default_message.rs:10)
+// error-pattern: static assertion failed: std :: mem :: size_of :: < T > () <= N (src/bin/default_message.rs:14)
+// error-pattern: static assertion failed: N > 1 (src/bin/default_message.rs:21)
+
+use static_assert_generic::*;
+
+fn foo<const N: usize>() {
+    static_assert!((N: usize) N == N);
+    static_assert!((N: usize) N != 0);
+}
+
+fn bar<const N: usize, T>() {
+    static_assert!((N: usize, T) {
+        N > 0 => "N must be non-zero!",
+        std::mem::size_of::<T>() <= N,
+    });
+}
+
+fn baz<const N: usize>() -> usize {
+    generic_const!((N: usize) -> usize N * 2 if N > 1)
+}
+
+fn main() {
+    foo::<0>();
+    bar::<4, u64>();
+    baz::<1>();
+}
@@ -0,0 +1,18 @@
+// error-pattern: N must be non-zero
+
+use static_assert_generic::*;
+
+enum NonZero<const N: usize> {}
+
+fn count<const N: usize>(data: &[u8], _: Checked<NonZero<N>>) -> usize {
+    data.len() / N
+}
+
+fn chunk_count<const N: usize>(data: &[u8]) -> usize {
+    let proof = static_assert!((N: usize) -> NonZero<N> N != 0 => "N must be non-zero");
+    count::<N>(data, proof)
+}
+
+fn main() {
+    chunk_count::<0>(&[]);
+}
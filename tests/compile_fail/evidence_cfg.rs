@@ -0,0 +1,13 @@
+// error-pattern: Assertions with `#[cfg(...)]` attributes can't produce evidence with `->`
+
+use static_assert_generic::*;
+
+enum NonZero<const N: usize> {}
+
+fn foo<const N: usize>() -> Checked<NonZero<N>> {
+    static_assert!(#[cfg(debug_assertions)] (N: usize) -> NonZero<N> N != 0)
+}
+
+fn main() {
+    foo::<0>();
+}
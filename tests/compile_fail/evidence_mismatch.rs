@@ -0,0 +1,19 @@
+// error-pattern: expected `Checked<NonZero<N>>`, found `Checked<Small<N>>`
+
+use static_assert_generic::*;
+
+enum NonZero<const N: usize> {}
+enum Small<const N: usize> {}
+
+fn count<const N: usize>(data: &[u8], _: Checked<NonZero<N>>) -> usize {
+    data.len() / N
+}
+
+fn small_count<const N: usize>(data: &[u8]) -> usize {
+    let proof = static_assert!((N: usize) -> Small<N> N < 64);
+    count::<N>(data, proof)
+}
+
+fn main() {
+    small_count::<4>(&[]);
+}
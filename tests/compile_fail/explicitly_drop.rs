@@ -0,0 +1,14 @@
+// error-pattern: error[E0080]
+// error-pattern: A must be dropped explicitly!
+
+use static_assert_generic::*;
+
+struct A<const B: u32>;
+
+impl<const B: u32> Drop for A<B> {
+    explicitly_drop!(B: u32 => "A must be dropped explicitly!");
+}
+
+fn main() {
+    let _a = A::<1>;
+}
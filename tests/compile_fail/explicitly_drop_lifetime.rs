@@ -0,0 +1,11 @@
+// error-pattern: `explicitly_drop!` can't depend on a lifetime
+
+use static_assert_generic::*;
+
+struct A<'a>(&'a u8);
+
+impl<'a> Drop for A<'a> {
+    explicitly_drop!('a => "A must be dropped explicitly!");
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// error-pattern: error[E0080]
+// error-pattern: N must be <= 64, got 100 (for x)
+
+use static_assert_generic::*;
+
+fn qux<const N: usize, const C: char>() {
+    static_assert!((N: usize, C: char) N <= 64 => "N must be <= 64, got {N} (for {C})");
+}
+
+fn main() {
+    qux::<100, 'x'>();
+}
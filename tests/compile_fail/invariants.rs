@@ -0,0 +1,27 @@
+// error-pattern: capacity out of range
+// error-pattern: N = 12 is not a power of two
+// error-pattern: T is too large for N = 16
+// error-pattern: note: elements are stored inline
+
+use static_assert_generic::*;
+
+#[derive(StaticInvariants)]
+#[invariant(N > 0 && N <= 4096, "capacity out of range")]
+#[invariant(N is power_of_two)]
+#[invariant(N * std::mem::size_of::<T>() <= 64, "T is too large for N = {N}", note: "elements are stored inline")]
+pub struct RingBuf<T, const N: usize> {
+    buf: [T; N],
+}
+
+impl<T: Copy, const N: usize> RingBuf<T, N> {
+    pub fn new(value: T) -> Self {
+        Self::__check();
+        RingBuf { buf: [value; N] }
+    }
+}
+
+fn main() {
+    RingBuf::<u8, 8192>::new(0);
+    RingBuf::<u8, 12>::new(0);
+    RingBuf::<u64, 16>::new(0);
+}
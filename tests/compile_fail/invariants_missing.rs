@@ -0,0 +1,8 @@
+// error-pattern: Expected at least one `#[invariant(...)]` attribute.
+
+use static_assert_generic::*;
+
+#[derive(StaticInvariants)]
+struct Empty<const N: usize>;
+
+fn main() {}
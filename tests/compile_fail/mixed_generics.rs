@@ -0,0 +1,15 @@
+// error-pattern: error[E0080]
+// error-pattern: N must be greater than M!
+// error-pattern: N must be half the size of T!
+
+use static_assert_generic::*;
+
+fn fie<const N: usize, const M: usize, T>() {
+    static_assert!((N: usize, M: usize) N > M => "N must be greater than M!");
+    static_assert!((N: usize, T) N == std::mem::size_of::<T>() / 2 => "N must be half the size of T!");
+}
+
+fn main() {
+    fie::<4, 7, u64>();
+    fie::<4, 1, u8>();
+}
@@ -0,0 +1,12 @@
+// error-pattern: error[E0080]
+// error-pattern: N must be greater than M!
+
+use static_assert_generic::*;
+
+fn baz<const N: usize, const M: usize>() {
+    static_assert!((N: usize, M: usize) N > M => "N must be greater than M!");
+}
+
+fn main() {
+    baz::<4, 7>();
+}
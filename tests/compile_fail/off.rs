@@ -0,0 +1,19 @@
+// build-pass
+// rustflags: --cfg static_assert_generic_off
+
+use static_assert_generic::*;
+
+fn foo<const N: usize>() {
+    static_assert!((N: usize) N != 0 => "N must be non-zero!");
+    static_assert!((N: usize) N in 1..=64);
+    static_assert_eq!((N: usize) N, 1);
+    static_assert_instantiations!(for (N: usize) in [0] N != 0);
+}
+
+static_assert_items! {
+    std::mem::size_of::<usize>() == 2 => "Only 16-bit platforms are supported!",
+}
+
+fn main() {
+    foo::<0>();
+}
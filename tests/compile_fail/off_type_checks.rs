@@ -0,0 +1,12 @@
+// error-pattern: error[E0308]: mismatched types
+// rustflags: --cfg static_assert_generic_off
+
+use static_assert_generic::*;
+
+fn foo<const N: usize>() {
+    static_assert!((N: usize) N + 1 => "N must be non-zero!");
+}
+
+fn main() {
+    foo::<0>();
+}
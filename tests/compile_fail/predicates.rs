@@ -0,0 +1,41 @@
+// error-pattern: N = 100 is not in 1..=64
+// error-pattern: N = 3 is not in [1, 2, 4, 8]
+// error-pattern: N = 12 is not a power of two
+// error-pattern: N = 12 is not a multiple of 8
+// error-pattern: N = 300 does not fit in u8
+// error-pattern: S = -1 does not fit in u32
+// error-pattern: S must be non-negative, got -1
+
+use static_assert_generic::*;
+
+fn range<const N: usize>() {
+    static_assert!((N: usize) N in 1..=64);
+}
+
+fn set<const N: usize>() {
+    static_assert!((N: usize) N in [1, 2, 4, 8]);
+}
+
+fn power_of_two<const N: u32>() {
+    static_assert!((N: u32) N is power_of_two);
+}
+
+fn multiple_of<const N: usize>() {
+    static_assert!((N: usize) N is multiple_of(8));
+}
+
+fn fits<const N: u64, const S: i16>() {
+    static_assert!((N: u64, S: i16) {
+        N fits u8,
+        S fits u32,
+        S in 0.. => "S must be non-negative, got {S}",
+    });
+}
+
+fn main() {
+    range::<100>();
+    set::<3>();
+    power_of_two::<12>();
+    multiple_of::<12>();
+    fits::<300, -1>();
+}
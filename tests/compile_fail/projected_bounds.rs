@@ -0,0 +1,42 @@
+// build-pass
+
+use static_assert_generic::*;
+
+trait HasCapacity {
+    const CAPACITY: usize;
+}
+
+impl HasCapacity for u8 {
+    const CAPACITY: usize = 8;
+}
+
+#[static_asserts]
+fn foo<T: IntoIterator>()
+where
+    T::Item: HasCapacity,
+{
+    static_assert!(<T::Item as HasCapacity>::CAPACITY > 0 => "Items must have a capacity!");
+}
+
+struct Wrapper<T>(T);
+
+#[static_asserts]
+impl<T> Wrapper<T>
+where
+    <T as IntoIterator>::Item: HasCapacity,
+    T: IntoIterator,
+{
+    fn capacity<const N: usize>(&self) -> usize
+    where
+        [u8; N]: Default,
+    {
+        static_assert!(<T::Item as HasCapacity>::CAPACITY >= N => "N = {N} exceeds the capacity!");
+        static_assert!(where [u8; N]: Copy; std::mem::size_of::<[u8; N]>() == N);
+        N
+    }
+}
+
+fn main() {
+    foo::<Vec<u8>>();
+    Wrapper(vec![1u8]).capacity::<4>();
+}
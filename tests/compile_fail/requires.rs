@@ -0,0 +1,29 @@
+// error-pattern: N must be non-zero
+// error-pattern: N = 12 is not a power of two
+// error-pattern: N must be at most 8
+// error-pattern: chunks of 16 elements don't fit in a cache line
+
+use static_assert_generic::*;
+
+#[requires(N != 0, "N must be non-zero")]
+#[requires(N is power_of_two)]
+#[requires((N) - 1 < 8, "N must be at most 8")]
+fn split<const N: usize>(data: &[u8]) -> usize {
+    data.chunks(N.max(1)).count()
+}
+
+struct Chunks<T, const N: usize>(Vec<[T; N]>);
+
+#[requires(std::mem::size_of::<T>() * N <= 64, "chunks of {N} elements don't fit in a cache line")]
+impl<T: Copy, const N: usize> Chunks<T, N> {
+    fn first(&self) -> [T; N] {
+        self.0[0]
+    }
+}
+
+fn main() {
+    split::<0>(&[]);
+    split::<12>(&[]);
+    split::<16>(&[]);
+    Chunks(vec![[0u64; 16]]).first();
+}
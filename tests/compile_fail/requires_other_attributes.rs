@@ -0,0 +1,21 @@
+// build-pass
+
+use static_assert_generic::*;
+
+/// Stands in for another crate with an attribute named `requires`.
+mod contracts {
+    pub use static_assert_generic::static_asserts as requires;
+}
+
+#[requires(N != 0, "N must be non-zero")]
+#[static_assert_generic::requires(N is power_of_two)]
+#[contracts::requires]
+fn split<const N: usize>(data: &[u8]) -> usize {
+    // Only compiles if `#[contracts::requires]` was left in place to fill in the generics.
+    static_assert!(N <= 64);
+    data.chunks(N).count()
+}
+
+fn main() {
+    split::<4>(&[0; 8]);
+}
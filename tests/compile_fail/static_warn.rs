@@ -0,0 +1,28 @@
+// warning-pattern: Header no longer fits in a cache line.
+// warning-pattern: N = 8 is slow, and will be phased out.
+// warning-pattern: help: use N >= 16
+// warning-pattern: static warning condition failed: 1 + 1 == 3
+// warning-pattern: N = 2 is too large for T
+// warning-pattern: N = 3 is not a power of two
+// warning-pattern: N = 4 * 2 is slow
+
+use static_assert_generic::*;
+
+struct Header { _bytes: [u8; 128] }
+
+const _: () = static_warn!(() std::mem::size_of::<Header>() <= 64 => "Header no longer fits in a cache line.");
+
+fn foo<const N: usize>() {
+    static_warn!(for (N: usize) in [8, 16, 32] N >= 16 => "N = {N} is slow, and will be phased out.", help: "use N >= 16");
+}
+
+fn main() {
+    foo::<16>();
+    static_warn!(1 + 1 == 3);
+    static_warn!(for (N: usize, T) in [(4, u8), (2, u64)] {
+        N * std::mem::size_of::<T>() <= 8 => "N = {N} is too large for T",
+        N is power_of_two,
+    });
+    static_warn!(for (N: usize) in [3, 4] N is power_of_two);
+    static_warn!(for (N: usize) in [4 * 2] N >= 16 => "N = {N} is slow");
+}
@@ -0,0 +1,12 @@
+// build-pass
+// rustflags: --cfg static_assert_generic_off
+
+#![deny(deprecated)]
+
+use static_assert_generic::*;
+
+const _: () = static_warn!(() 1 + 1 == 3 => "Math is broken!");
+
+fn main() {
+    static_warn!(for (N: usize) in [8, 16] N >= 16 => "N = {N} is slow");
+}
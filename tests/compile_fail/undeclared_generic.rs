@@ -0,0 +1,12 @@
+// expansion: struct
+// error-pattern: can't use generic parameters from outer item
+
+use static_assert_generic::*;
+
+fn bar<const N: usize>() {
+    static_assert!(() N != 0 => "N must be a non-zero value!");
+}
+
+fn main() {
+    bar::<1>();
+}
@@ -0,0 +1,15 @@
+// error-pattern: `N` needs to be a declared const generic of integer type, such as `(N: usize)`.
+// error-pattern: `C` needs to be a declared const generic of integer type, such as `(C: usize)`.
+// error-pattern: Expected `power_of_two` or `multiple_of(..)`.
+
+use static_assert_generic::*;
+
+fn foo<const N: usize, const C: char>() {
+    static_assert!((C: char) N in 1..=64);
+    static_assert!((C: char) C in ['a', 'b']);
+    static_assert!((N: usize) N is even);
+}
+
+fn main() {
+    foo::<1, 'a'>();
+}
@@ -0,0 +1,11 @@
+// error-pattern: Syntax error, if you want to make the type unsized do T? instead of T: ?Sized.
+
+use static_assert_generic::*;
+
+fn eggs<T: ?Sized>() {
+    static_assert!((T: ?Sized) true);
+}
+
+fn main() {
+    eggs::<str>();
+}
@@ -0,0 +1,11 @@
+// error-pattern: Syntax error, if you want to make the type unsized do T? instead of T: ?Sized.
+
+use static_assert_generic::*;
+
+fn eggs<T: ?Sized + std::fmt::Debug>() {
+    static_assert!((T: std::fmt::Debug + ?Sized) true);
+}
+
+fn main() {
+    eggs::<str>();
+}
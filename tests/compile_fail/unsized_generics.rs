@@ -0,0 +1,32 @@
+// error-pattern: error[E0080]
+// error-pattern: T must not be a slice!
+// error-pattern: U must be displayable in 4 bytes!
+
+use static_assert_generic::*;
+
+trait Kind {
+    const SLICE: bool;
+}
+
+impl Kind for str {
+    const SLICE: bool = false;
+}
+
+impl Kind for [u8] {
+    const SLICE: bool = true;
+}
+
+fn foo<T: ?Sized + Kind>() {
+    static_assert!((T?: Kind) !T::SLICE => "T must not be a slice!");
+}
+
+#[static_asserts]
+fn bar<T: ?Sized, U: ?Sized + std::fmt::Display>(_: &U) {
+    static_assert!(std::mem::size_of::<&U>() <= 4 => "U must be displayable in 4 bytes!");
+}
+
+fn main() {
+    foo::<str>();
+    foo::<[u8]>();
+    bar::<[u8], str>("");
+}
@@ -0,0 +1,27 @@
+// error-pattern: error[E0080]
+// error-pattern: T must have a capacity!
+// error-pattern: Items must have a capacity!
+
+use static_assert_generic::*;
+
+trait HasCapacity {
+    const CAPACITY: usize;
+}
+
+impl HasCapacity for u8 {
+    const CAPACITY: usize = 0;
+}
+
+fn foo<T>() where T: HasCapacity {
+    static_assert!((T) where T: HasCapacity; T::CAPACITY > 0 => "T must have a capacity!");
+}
+
+#[static_asserts]
+fn bar<T: IntoIterator>() where T::Item: HasCapacity {
+    static_assert!(<T::Item as HasCapacity>::CAPACITY > 0 => "Items must have a capacity!");
+}
+
+fn main() {
+    foo::<u8>();
+    bar::<Vec<u8>>();
+}
@@ -2,14 +2,60 @@
 
 use static_assert_generic::*;
 
-const FOO: () = static_assert!(() 1 + 1 == 2);
+#[allow(clippy::eq_op)] const FOO: () = static_assert!(() 1 + 1 == 2);
 #[allow(clippy::assertions_on_constants, clippy::eq_op)] const BAR: () = assert!(1 + 1 == 2);
 
+static_assert_items! {
+    std::mem::size_of::<u32>() == 4 => "u32 must be 4 bytes long!",
+    std::mem::align_of::<u64>() <= 8,
+}
+
+trait HasCapacity { const CAPACITY: usize; }
+impl HasCapacity for u8 { const CAPACITY: usize = 0; }
+impl HasCapacity for u16 { const CAPACITY: usize = 4; }
+
+static_assert_instantiations!(for (T: HasCapacity) in [u8, u16] T::CAPACITY <= 4);
+
+const _: () = static_warn!(() std::mem::size_of::<u64>() <= 8 => "u64 got larger!");
+
+#[derive(StaticInvariants)]
+#[invariant(N > 0 && N <= 4096, "capacity out of range")]
+#[invariant(N is power_of_two)]
+#[invariant(std::mem::size_of::<T>() <= 8, "T is too large for N = {N}", note: "elements are stored inline")]
+struct RingBuf<T: Copy, const N: usize> where T: Default {
+    buf: [T; N],
+}
+
+impl<T: Copy + Default, const N: usize> RingBuf<T, N> {
+    const fn with(buf: [T; N]) -> Self {
+        Self::__check();
+        RingBuf { buf }
+    }
+}
+
+struct Handle(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+assert_impl!(Handle: Send + Sync + 'static);
+assert_impl!((T: Send) Vec<T>: Send + Default => "Vec<T> must be Send");
+assert_impl!((T: HasCapacity + Sync, 'a) &'a T: Send + Copy);
+assert_impl!((T: IntoIterator) where T::Item: Send; Vec<T::Item>: Send);
+assert_impl!(<U: ?Sized + Send> Box<U>: Send);
+
+struct Guard(std::marker::PhantomData<*const ()>);
+
+assert_not_impl!(Guard: Send);
+assert_not_impl!(Guard: any(Send, Sync, Clone) => "guards must stay on their thread");
+assert_not_impl!(std::rc::Rc<u8>: all(Send, Clone));
+assert_not_impl!(std::cell::Cell<u8>: any(Sync, all(Copy, Send, Default)));
+assert_not_impl!((T) Vec<T>: any(Send, Copy));
+assert_not_impl!((T: Send, 'a) &'a std::cell::Cell<T>: Send);
+
 struct A<const B: u32> {}
 impl<const B: u32> Drop for A<B> {
     explicitly_drop!(B: u32);
 }
 
+// The cases commented out as failing are built by `tests/compile_fail.rs`, from the fixtures in `tests/compile_fail`.
 #[test]
 fn test() {
 
@@ -31,6 +77,14 @@ fn test() {
     // foo::<0>();
 
 
+    fn qux<const N: usize, const C: char>() {
+        static_assert!((N: usize, C: char) N <= 64 => "N must be <= 64, got {N} (for {C})");
+    }
+    qux::<12, 'x'>();
+    // fails at "N must be <= 64, got 100 (for x)"
+    // qux::<100, 'x'>();
+
+
     // fails
     // fn bar<const N: usize>() {
     //     static_assert!(() N != 0 => "N must be a non-zero value!"); 
@@ -40,7 +94,9 @@ fn test() {
 
     fn baz<const N: usize, const M: usize>() {
        static_assert!((N: usize, M: usize) N > M => "N must be greater than M!");
+       static_assert!((N: usize, M: usize) M != 0 && N % M == 0 && (N > 2 || !(M >= 4)));
     }
+    baz::<6, 3>();
     // fails
     // baz::<4, 7>();
 
@@ -67,4 +123,201 @@ fn test() {
 
     // fie::<4, 7, u64>(); // fails at "N must be greater than M!"
     // fie::<4, 1, u8>(); // fails at "N must be half the size_of T!"
+
+
+
+    fn foe<const N: usize, const M: usize, T>() {
+        static_assert_gt!((N: usize, M: usize) N, M => "N must be greater than M!");
+        static_assert_eq!((N: usize, T) N, std::mem::size_of::<T>() / 2);
+        static_assert_ne!((M: usize) M, 0);
+        static_assert_le!((M: usize) -1, M as i64);
+    }
+    foe::<4, 1, u64>();
+
+    // foe::<4, 7, u64>(); // fails at "assertion `left > right` failed: N must be greater than M!\n  left: 4\n right: 7"
+    // foe::<4, 1, u8>(); // fails at "assertion `left == right` failed\n  left: 4\n right: 0"
+
+
+
+    #[static_asserts]
+    fn fum<const N: usize, const M: usize, T: ?Sized>() {
+        static_assert!(N > M => "N must be greater than M!");
+        static_assert!(std::mem::size_of::<&T>() >= N);
+        static_assert_ne!(M, 0);
+        static_assert!((1 + 2) < 17);
+        static_assert!(((N) - 1 >= M));
+    }
+    fum::<4, 1, u8>();
+    // fum::<4, 7, u8>(); // fails at "N must be greater than M!"
+
+
+
+    fn fo<'a, T: ?Sized>(_x: &'a T) {
+        static_assert!((T?, 'a) std::mem::size_of::<&'a T>() == std::mem::size_of::<usize>() => "References to T must be thin!");
+    }
+    fo(&1u8);
+    // fo("str"); // fails at "References to T must be thin!"
+
+
+
+    fn fa<T: HasCapacity, const N: usize>() {
+        static_assert!((T: HasCapacity, N: usize) T::CAPACITY >= N => "T can't hold {N} elements!");
+    }
+    fa::<u16, 4>();
+    // fa::<u8, 1>(); // fails at "T can't hold 1 elements!"
+
+    #[static_asserts]
+    fn fe<T, U>() where T: HasCapacity + Copy, U: ?Sized + std::fmt::Debug {
+        static_assert!(T::CAPACITY > 0);
+        static_assert!(std::mem::size_of::<&U>() >= std::mem::size_of::<T>());
+    }
+    fe::<u16, str>();
+
+
+
+    fn fi<T, U>() where T: IntoIterator + Into<U>, T::Item: HasCapacity {
+        static_assert!((T: IntoIterator, U) where T: Into<U>, T::Item: HasCapacity; <T::Item as HasCapacity>::CAPACITY > 0);
+        static_assert_eq!((T: IntoIterator) where T::Item: HasCapacity; <T::Item as HasCapacity>::CAPACITY, 4);
+    }
+    fi::<Vec<u16>, Vec<u16>>();
+    // fi::<Vec<u8>, Vec<u8>>(); // fails at "static assertion failed: < T :: Item as HasCapacity > :: CAPACITY > 0 (tests/tests.rs:143)"
+
+
+
+    fn fy<'a, 'b: 'a, const N: usize, T: ?Sized + std::fmt::Debug + 'a, U: HasCapacity>(_x: &'a T, _y: &'b U) {
+        static_assert!(<'a, 'b: 'a, const N: usize, T: ?Sized + std::fmt::Debug + 'a, U: HasCapacity = u16> N <= U::CAPACITY => "N = {N} is too large!");
+        static_assert_ge!(<'a, T: ?Sized + 'a> std::mem::size_of::<&'a T>(), 8);
+    }
+    fy::<4, str, u16>("x", &0);
+    // fy::<8, str, u16>("x", &0); // fails at "N = 8 is too large!"
+
+
+
+    fn fu<const N: usize, T>() {
+        static_assert!((N: usize, T) {
+            N > 0 => "N must be non-zero!",
+            N & 1 == 0 => "N = {N} must be even!", help: "round N = {N} up to {N}+1",
+            std::mem::size_of::<T>() <= N,
+        });
+        static_assert!((N: usize) N <= 64 => "N = {N} is too large!", help: "use a Vec instead", note: "N is the length of an array");
+    }
+    fu::<4, u32>();
+    // fu::<3, u8>(); // fails at "N = 3 must be even!"
+    // fu::<4, u64>(); // fails at "static assertion failed: std :: mem :: size_of :: < T > () <= N (tests/tests.rs:161)"
+
+
+
+    fn fr<const N: usize, T>() -> usize {
+        generic_const!((N: usize, T) -> usize N * std::mem::size_of::<T>() if N <= 64 => "N = {N} is too large!")
+    }
+    assert_eq!(fr::<3, u32>(), 12);
+    // fr::<100, u8>(); // fails at "N = 100 is too large!"
+    const LEN: usize = generic_const!(() -> usize 4 * 2);
+    assert_eq!([0u8; LEN].len(), 8);
+
+
+
+    fn fl<const N: usize, T>() {
+        static_assert!((N: usize, T) N * std::mem::size_of::<T>() <= 16 => "N = {N} is too large!");
+        static_assert_instantiations!(for (N: usize, T) in [(4, u32), (2, u64), (16, u8)] N * std::mem::size_of::<T>() <= 16 => "N = {N} is too large!");
+        // static_assert_instantiations!(for (N: usize, T) in [(4, u32), (4, u64)] N * std::mem::size_of::<T>() <= 16 => "N = {N} is too large!"); // fails at "N = 4 is too large!" under `cargo check`
+    }
+    fl::<4, u32>();
+
+
+
+    fn fw<const N: usize, const S: i8, T>() {
+        static_assert!((N: usize, S: i8, T) {
+            N in 0..=64,
+            N in [1, 2, 4, 8, 16],
+            N is power_of_two,
+            N is multiple_of(std::mem::align_of::<T>()),
+            N fits u8,
+            S in -8..8 => "S = {S} is out of range!",
+            S fits u8 => "S must be positive!", help: "use the absolute value",
+        });
+    }
+    fw::<4, 3, u32>();
+    // fw::<100, 3, u32>(); // fails at "N = 100 is not in 0..=64"
+    // fw::<2, 3, u32>(); // fails at "N = 2 is not a multiple of std::mem::align_of::<T>()"
+
+
+
+    fn fv<const N: usize>() {
+        static_warn!(for (N: usize) in [16, 32, 64] {
+            N >= 16 => "N = {N} is slow, and will be phased out.",
+            N is power_of_two,
+        });
+        // static_warn!(for (N: usize) in [8, 16] N >= 16); // warns with "static warning condition failed: N >= 16"
+    }
+    fv::<8>();
+
+
+
+    #[static_asserts]
+    fn fx<const N: usize>() {
+        static_assert!(#[cfg(test)] N != 0);
+        // `any()` never holds, so this assertion is only type-checked.
+        static_assert!(#[cfg(any())] N == 0 => "N must be zero!");
+        static_assert_ne!(#[cfg(debug_assertions)] (N: usize) N, 0);
+    }
+    fx::<2>();
+
+
+
+    #[requires(N != 0, "N must be non-zero")]
+    #[requires(N is power_of_two)]
+    #[requires((N) - 1 < 8, "N must be at most 8")]
+    fn fz<const N: usize>(data: &[u8]) -> usize {
+        data.chunks(N).count()
+    }
+    assert_eq!(fz::<4>(&[0; 12]), 3);
+    // fz::<0>(&[]); // fails at "N must be non-zero"
+
+    struct Chunks<T, const N: usize>(Vec<[T; N]>);
+
+    #[requires(std::mem::size_of::<T>() * N <= 64, "chunks of {N} elements don't fit in a cache line")]
+    impl<T: Copy, const N: usize> Chunks<T, N> {
+        fn push(&mut self, chunk: [T; N]) {
+            self.0.push(chunk);
+        }
+
+        fn get<const I: usize>(&self) -> [T; N] {
+            self.0[I]
+        }
+    }
+    let mut chunks = Chunks(Vec::new());
+    chunks.push([0u64; 8]);
+    assert_eq!(chunks.get::<0>(), [0; 8]);
+    // Chunks(vec![[0u64; 16]]).get::<0>(); // fails at "chunks of 16 elements don't fit in a cache line"
+
+    #[requires(<T::Item as HasCapacity>::CAPACITY > 0, "items must have a capacity")]
+    fn total_capacity<T: IntoIterator>(items: T) -> usize where T::Item: HasCapacity {
+        items.into_iter().count() * <T::Item as HasCapacity>::CAPACITY
+    }
+    assert_eq!(total_capacity([1u16, 2]), 8);
+    // total_capacity([1u8]); // fails at "items must have a capacity"
+
+
+
+    enum NonZero<const N: usize> {}
+
+    fn count<const N: usize>(data: &[u8], _: Checked<NonZero<N>>) -> usize {
+        data.len() / N
+    }
+
+    #[static_asserts]
+    fn chunk_count<const N: usize>(data: &[u8]) -> usize {
+        let proof: Checked<NonZero<N>> = static_assert!(-> _ N != 0 => "N must be non-zero");
+        count::<N>(data, proof) + count::<N>(data, proof)
+    }
+    assert_eq!(chunk_count::<4>(&[0; 8]), 4);
+    assert_eq!(std::mem::size_of_val(&static_assert!(() -> NonZero<4> { 4 > 0, 4 < 10 })), 0);
+    // chunk_count::<0>(&[]); // fails at "N must be non-zero"
+
+
+
+    let ring = RingBuf::<u32, 16>::with([0; 16]);
+    assert_eq!(ring.buf.len(), 16);
+    // RingBuf::<u32, 12>::with([0; 12]); // fails at "N = 12 is not a power of two"
 }